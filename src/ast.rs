@@ -31,10 +31,27 @@ pub enum TupleLike {
     Array(Array),
     List(List),
 }
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub struct ConstNum(pub usize);
 #[derive(Debug)]
-pub struct Len(pub String);
+pub struct Len {
+    // The length as written, e.g. "n+1".
+    // A numeric literal is normalized, e.g. "1_000" is "1000".
+    pub expr: String,
+    // Set if the length is a numeric literal.
+    pub const_num: Option<ConstNum>,
+    // The upper bound in "n <= 200000".
+    pub capacity: Option<u64>,
+}
+impl Len {
+    pub fn new(expr: String) -> Len {
+        Len {
+            expr,
+            const_num: None,
+            capacity: None,
+        }
+    }
+}
 #[derive(Debug)]
 pub struct Matrix(pub TupleLike, pub Len);
 #[derive(Debug)]
@@ -0,0 +1,230 @@
+use super::*;
+
+// Arrays are malloc'd pointers without length. The length of a list
+// is kept in a variable named with "_len" suffix.
+// Arrays of a constant length are pointers too because a C array can't be
+// assigned to a struct member or a matrix element.
+// Strings are read char by char into allocations grown by realloc
+// so a token of any length fits. The reader is a function at file scope.
+pub struct C;
+impl stream::Lang for C {
+    fn prelude() -> Code {
+        let read_str = shared_var("read_str");
+        let mut code = vec![];
+        code.push(format!("static char* {read_str}(void) {{"));
+        code.push("	size_t len = 0, cap = 16;".to_string());
+        code.push("	char* s = malloc(cap);".to_string());
+        code.push(format!("	{}", check_alloc("s", None)));
+        code.push("	int c = getchar();".to_string());
+        code.push("	while (isspace(c)) c = getchar();".to_string());
+        code.push("	while (c != EOF && !isspace(c)) {".to_string());
+        code.push("		if (len + 1 == cap) {".to_string());
+        code.push("			cap *= 2;".to_string());
+        code.push("			s = realloc(s, cap);".to_string());
+        code.push(format!("			{}", check_alloc("s", None)));
+        code.push("		}".to_string());
+        code.push("		s[len++] = c;".to_string());
+        code.push("		c = getchar();".to_string());
+        code.push("	}".to_string());
+        code.push("	s[len] = '\\0';".to_string());
+        code.push("	return s;".to_string());
+        code.push("}".to_string());
+        global_code(code);
+        vec![]
+    }
+    fn unit_type(bind: Bind, ast: &ast::UnitType) -> Code {
+        let mut code = vec![];
+        let ty = typing::unit_type(ast);
+        code.push(format!("{ty} {bind};"));
+        code.append(&mut scan_unit_type(&bind.0, ast));
+        code
+    }
+    fn array(bind: Bind, ast: &ast::Array) -> Code {
+        let mut code = vec![];
+        let ty = typing::unit_type(&ast.0);
+        let n = Index(ast.1.expr.clone());
+        code.push(format!("{ty}* {bind} = malloc(sizeof({ty}) * ({n}));"));
+        code.push(check_alloc(&bind.0, Some(&n)));
+        let k = new_var();
+        code.push(format!("for (int {k}=0; {k}<{n}; {k}++) {{"));
+        let v = format!("{bind}[{k}]");
+        append_code(&mut code, "\t", scan_unit_type(&v, &ast.0));
+        code.push("}".to_string());
+        code
+    }
+    fn list(bind: Bind, ast: &ast::List) -> Code {
+        let mut code = vec![];
+        let n = Bind(ast.1.expr.clone());
+        code.append(&mut Self::unit_type(n.clone(), &ast::UnitType::Int));
+        let len = ast::Len::new(n.0.clone());
+        code.append(&mut Self::array(bind.clone(), &ast::Array(ast.0, len)));
+        code.push(format!("int {bind}_len = {n};"));
+        code
+    }
+    fn matrix(bind: Bind, ast: &ast::Matrix) -> Result<Code, Error> {
+        let mut code = vec![];
+        let n = Index(ast.1.expr.clone());
+        let ty = match &ast.0 {
+            ast::TupleLike::Tuple(x) if x.0.len() > 1 => {
+                let ty = format!("struct {bind}_t");
+                code.push(format!("{ty} {{ {} }};", typing::fields(x)));
+                ty
+            }
+            x => typing::tuple_like(x),
+        };
+        code.push(format!("{ty}* {bind} = malloc(sizeof({ty}) * ({n}));"));
+        code.push(check_alloc(&bind.0, Some(&n)));
+        // A tuple of one list is the list itself so it needs the lengths too.
+        let is_list = match &ast.0 {
+            ast::TupleLike::List(_) => true,
+            ast::TupleLike::Tuple(x) => matches!(x.0[..], [TupleElem::List(_)]),
+            _ => false,
+        };
+        if is_list {
+            code.push(format!("int* {bind}_len = malloc(sizeof(int) * ({n}));"));
+            code.push(check_alloc(&format!("{bind}_len"), Some(&n)));
+        }
+        let k = new_var();
+        code.push(format!("for (int {k}=0; {k}<{n}; {k}++) {{"));
+
+        let mut inner_code = vec![];
+        match &ast.0 {
+            ast::TupleLike::Tuple(ast::Tuple(elems)) if elems.len() > 1 => {
+                let mut inner = vec![];
+                for elem in elems {
+                    let v = new_var();
+                    match elem {
+                        TupleElem::UnitType(e) => {
+                            inner_code.append(&mut Self::unit_type(v.clone(), e));
+                            inner.push(format!("{v}"));
+                        }
+                        TupleElem::Array(e) => {
+                            inner_code.append(&mut Self::array(v.clone(), e));
+                            inner.push(format!("{v}"));
+                        }
+                        TupleElem::List(e) => {
+                            inner_code.append(&mut Self::list(v.clone(), e));
+                            inner.push(format!("{v}, {v}_len"));
+                        }
+                    }
+                }
+                let inner = inner.join(", ");
+                inner_code.push(format!("{bind}[{k}] = ({ty}){{ {inner} }};"));
+            }
+            _ => {
+                let t = new_var();
+                inner_code.append(&mut Self::tuple_like(t.clone(), &ast.0)?);
+                inner_code.push(format!("{bind}[{k}] = {t};"));
+                if is_list {
+                    inner_code.push(format!("{bind}_len[{k}] = {t}_len;"));
+                }
+            }
+        }
+        append_code(&mut code, "\t", inner_code);
+
+        code.push("}".to_string());
+        Ok(code)
+    }
+    fn tuple(bind: Bind, elems: Vec<(&ast::TupleElem, Bind)>) -> Result<Code, Error> {
+        let mut code = vec![];
+        let n = elems.len();
+        if n == 1 {
+            let (e, v) = &elems[0];
+            let ty = typing::tuple_elem(e);
+            code.push(format!("{ty} {bind} = {v};"));
+            if let TupleElem::List(_) = e {
+                code.push(format!("int {bind}_len = {v}_len;"));
+            }
+            return Ok(code);
+        }
+        let mut fields = vec![];
+        let mut inner = vec![];
+        for (i, (e, v)) in elems.into_iter().enumerate() {
+            let ty = typing::tuple_elem(e);
+            fields.push(format!("{ty} f{i};"));
+            inner.push(format!("{v}"));
+            if let TupleElem::List(_) = e {
+                fields.push(format!("int f{i}_len;"));
+                inner.push(format!("{v}_len"));
+            }
+        }
+        let fields = fields.join(" ");
+        let inner = inner.join(", ");
+        code.push(format!("struct {{ {fields} }} {bind} = {{ {inner} }};"));
+        Ok(code)
+    }
+}
+fn scan_unit_type(v: &str, ty: &ast::UnitType) -> Code {
+    let mut code = vec![];
+    match ty {
+        ast::UnitType::Int => {
+            code.push(format!("scanf(\"%d\", &{v});"));
+        }
+        ast::UnitType::Int0 => {
+            code.push(format!("scanf(\"%d\", &{v});"));
+            code.push(format!("{v}--;"));
+        }
+        ast::UnitType::Float => {
+            code.push(format!("scanf(\"%lf\", &{v});"));
+        }
+        ast::UnitType::Str => {
+            code.push(format!("{v} = {}();", shared_var("read_str")));
+        }
+    }
+    code
+}
+// malloc(0) may return NULL so it is only an error for a positive count.
+fn check_alloc(v: &str, n: Option<&Index>) -> String {
+    let cond = match n {
+        Some(n) => format!("{v} == NULL && ({n}) > 0"),
+        None => format!("{v} == NULL"),
+    };
+    format!("if ({cond}) {{ fputs(\"out of memory\\n\", stderr); exit(1); }}")
+}
+type Type = String;
+mod typing {
+    use super::*;
+    pub fn unit_type(ty: &ast::UnitType) -> Type {
+        match ty {
+            ast::UnitType::Int => "int".to_string(),
+            ast::UnitType::Int0 => "int".to_string(),
+            ast::UnitType::Float => "double".to_string(),
+            ast::UnitType::Str => "char*".to_string(),
+        }
+    }
+    pub fn array(ty: &ast::Array) -> Type {
+        let inner = unit_type(&ty.0);
+        format!("{inner}*")
+    }
+    pub fn list(ty: &ast::List) -> Type {
+        let inner = unit_type(&ty.0);
+        format!("{inner}*")
+    }
+    pub fn tuple_elem(ty: &ast::TupleElem) -> Type {
+        match ty {
+            TupleElem::Array(x) => array(x),
+            TupleElem::List(x) => list(x),
+            TupleElem::UnitType(x) => unit_type(x),
+        }
+    }
+    // The members of the struct made for a tuple.
+    pub fn fields(ty: &ast::Tuple) -> String {
+        let mut out = vec![];
+        for (i, e) in ty.0.iter().enumerate() {
+            out.push(format!("{} f{i};", tuple_elem(e)));
+            if let TupleElem::List(_) = e {
+                out.push(format!("int f{i}_len;"));
+            }
+        }
+        out.join(" ")
+    }
+    // A tuple of one element is the element itself.
+    // Other tuples are given their struct types by the caller.
+    pub fn tuple_like(ty: &ast::TupleLike) -> Type {
+        match ty {
+            ast::TupleLike::Array(x) => array(x),
+            ast::TupleLike::List(x) => list(x),
+            ast::TupleLike::Tuple(x) => tuple_elem(&x.0[0]),
+        }
+    }
+}
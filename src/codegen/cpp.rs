@@ -35,9 +35,12 @@ impl readline::Lang for Cpp {
         let i = range.0;
         let j = range.1;
         let ty = typing::array(&ast);
-        let n = Index(ast.1 .0.clone());
+        let n = Index(ast.1.expr.clone());
+        let fixed = ast.1.const_num.is_some();
         code.push(format!("{ty} {bind};"));
-        code.push(format!("{bind}.reserve({n});"));
+        if !fixed {
+            code.push(format!("{bind}.reserve({n});"));
+        }
 
         let k = new_var();
         code.push(format!("for (int {k}={i}; {k}<{j}; {k}++) {{"));
@@ -46,7 +49,12 @@ impl readline::Lang for Cpp {
         let unit_val = new_var();
         let v = format!("{xs}[{k}]");
         inner_code.append(&mut scan_unit_type(unit_val.clone(), &ast.0, &v));
-        inner_code.push(format!("{bind}.push_back({unit_val});"));
+        if fixed {
+            let pos = Index(k.0) - i;
+            inner_code.push(format!("{bind}[{pos}] = {unit_val};"));
+        } else {
+            inner_code.push(format!("{bind}.push_back({unit_val});"));
+        }
         append_code(&mut code, "\t", inner_code);
 
         code.push(format!("}}"));
@@ -55,7 +63,7 @@ impl readline::Lang for Cpp {
     fn matrix(bind: Bind, ast: &ast::Matrix) -> Result<Code, super::Error> {
         let mut code = vec![];
         let ty = format!("std::vector<{}>", typing::tuple_like(&ast.0));
-        let n = Index(ast.1 .0.clone());
+        let n = Index(ast.1.expr.clone());
         code.push(format!("{ty} {bind};"));
         code.push(format!("{bind}.reserve({n});"));
 
@@ -92,6 +100,133 @@ impl readline::Lang for Cpp {
     }
 }
 
+// Arrays and matrices with a capacity hint like `n <= 200000` are read into
+// static arrays declared at file scope, so the template has to have a { global } hook.
+// The length and the capacity are named after the array, e.g. `a_len` and `a_cap`.
+// Other types are read by Cpp with vectors.
+pub struct CppStatic;
+impl readline::Lang for CppStatic {
+    fn read_line(bind: Bind) -> (Code, Index) {
+        <Cpp as readline::Lang>::read_line(bind)
+    }
+    fn unit_type(bind: Bind, ast: &ast::UnitType, source: Slice) -> Code {
+        <Cpp as readline::Lang>::unit_type(bind, ast, source)
+    }
+    fn array(bind: Bind, ast: &ast::Array, source: Slice) -> Code {
+        <Cpp as readline::Lang>::array(bind, ast, source)
+    }
+    fn matrix(bind: Bind, ast: &ast::Matrix) -> Result<Code, super::Error> {
+        let cap = match ast.1.capacity {
+            Some(cap) => cap,
+            None => return <Cpp as readline::Lang>::matrix(bind, ast),
+        };
+        let mut code = vec![];
+        let n = Index(ast.1.expr.clone());
+        match &ast.0 {
+            ast::TupleLike::Array(row) if row.1.capacity.is_some() => {
+                let ty = typing::unit_type(&row.0);
+                declare_static(&bind, &ty, cap, row.1.capacity);
+            }
+            row => {
+                let ty = typing::tuple_like(row);
+                declare_static(&bind, &ty, cap, None);
+            }
+        }
+        code.append(&mut static_len(&bind, &n));
+
+        let k = new_var();
+        code.push(format!("for (int {k}=0; {k}<{bind}_len; {k}++) {{"));
+
+        let line = new_var();
+        let (read_line, m) = Self::read_line(line.clone());
+        append_code(&mut code, "\t", read_line);
+
+        match &ast.0 {
+            ast::TupleLike::Array(row) if row.1.capacity.is_some() => {
+                let l = new_var();
+                let row_n = Index(row.1.expr.clone());
+                let mut inner_code = vec![];
+                let unit_val = new_var();
+                let v = format!("{line}[{l}]");
+                inner_code.append(&mut scan_unit_type(unit_val.clone(), &row.0, &v));
+                inner_code.push(format!("{bind}[{k}][{l}] = {unit_val};"));
+                code.push(format!("\tassert({row_n} <= {bind}_row_cap);"));
+                code.push(format!("\tfor (int {l}=0; {l}<{row_n}; {l}++) {{"));
+                append_code(&mut code, "\t\t", inner_code);
+                code.push("\t}".to_string());
+            }
+            row => {
+                let tuple = new_var();
+                let slice = Slice(line, Range(Index::zero(), m));
+                let inner_code = <Cpp as readline::Lang>::tuple_like(tuple.clone(), row, slice)?;
+                append_code(&mut code, "\t", inner_code);
+                code.push(format!("\t{bind}[{k}] = {tuple};"));
+            }
+        }
+
+        code.push("}".to_string());
+        Ok(code)
+    }
+    fn tuple(bind: Bind, elems: Vec<(&ast::TupleElem, Bind)>) -> Result<Code, super::Error> {
+        <Cpp as readline::Lang>::tuple(bind, elems)
+    }
+    // Only arrays at the top level become static.
+    // Arrays in a tuple are read by Cpp.
+    fn tuple_like(bind: Bind, ast: &ast::TupleLike, source: Slice) -> Result<Code, super::Error> {
+        match ast {
+            ast::TupleLike::Array(x) if x.1.capacity.is_some() => {
+                let mut code = vec![];
+                let Slice(xs, range) = source;
+                let i = range.0;
+                let j = range.1;
+                let ty = typing::unit_type(&x.0);
+                let cap = x.1.capacity.unwrap();
+                let n = Index(x.1.expr.clone());
+                declare_static(&bind, &ty, cap, None);
+                code.append(&mut static_len(&bind, &n));
+
+                let k = new_var();
+                code.push(format!("for (int {k}={i}; {k}<{j}; {k}++) {{"));
+
+                let mut inner_code = vec![];
+                let unit_val = new_var();
+                let v = format!("{xs}[{k}]");
+                inner_code.append(&mut scan_unit_type(unit_val.clone(), &x.0, &v));
+                let pos = Index(k.0) - i;
+                inner_code.push(format!("{bind}[{pos}] = {unit_val};"));
+                append_code(&mut code, "\t", inner_code);
+
+                code.push("}".to_string());
+                Ok(code)
+            }
+            _ => <Cpp as readline::Lang>::tuple_like(bind, ast, source),
+        }
+    }
+}
+
+// Also used by CppStreamStatic.
+pub fn declare_static(bind: &Bind, ty: &str, cap: u64, row_cap: Option<u64>) {
+    let mut code = vec![];
+    code.push(format!("constexpr int {bind}_cap = {cap};"));
+    match row_cap {
+        Some(row_cap) => {
+            code.push(format!("constexpr int {bind}_row_cap = {row_cap};"));
+            code.push(format!("static {ty} {bind}[{bind}_cap][{bind}_row_cap];"));
+        }
+        None => {
+            code.push(format!("static {ty} {bind}[{bind}_cap];"));
+        }
+    }
+    global_code(code);
+}
+// The assert catches an input which breaks the capacity hint.
+pub fn static_len(bind: &Bind, n: &Index) -> Code {
+    let mut code = vec![];
+    code.push(format!("int {bind}_len = {n};"));
+    code.push(format!("assert({bind}_len <= {bind}_cap);"));
+    code
+}
+
 fn scan_unit_type(bind: Bind, ast: &ast::UnitType, s: &str) -> Code {
     let mut code = vec![];
     let ty = typing::unit_type(&ast);
@@ -127,7 +262,10 @@ pub mod typing {
     }
     pub fn array(ty: &ast::Array) -> Type {
         let inner = unit_type(&ty.0);
-        format!("std::vector<{inner}>")
+        match ty.1.const_num {
+            Some(ast::ConstNum(n)) => format!("std::array<{inner}, {n}>"),
+            None => format!("std::vector<{inner}>"),
+        }
     }
     pub fn list(ty: &ast::List) -> Type {
         let inner = unit_type(&ty.0);
@@ -0,0 +1,125 @@
+use super::cpp::typing;
+use super::*;
+
+// Reads stdin by fread into a buffer instead of std::cin.
+// Vectors are allocated with the length and filled in place.
+pub struct CppFast;
+impl stream::Lang for CppFast {
+    fn prelude() -> Code {
+        let mut code = vec![];
+        let buf = new_var();
+        let len = new_var();
+        let pos = new_var();
+        let read_byte = shared_var("read_byte");
+        let read_token = shared_var("read_token");
+        let read_int = shared_var("read_int");
+        let read_double = shared_var("read_double");
+        code.push(format!("static char {buf}[1 << 16];"));
+        code.push(format!("size_t {len} = 0, {pos} = 0;"));
+        code.push(format!("auto {read_byte} = [&]() -> int {{"));
+        code.push(format!("\tif ({pos} == {len}) {{"));
+        code.push(format!(
+            "\t\t{len} = fread({buf}, 1, sizeof({buf}), stdin); {pos} = 0;"
+        ));
+        code.push(format!("\t\tif ({len} == 0) return -1;"));
+        code.push("\t}".to_string());
+        code.push(format!("\treturn (unsigned char){buf}[{pos}++];"));
+        code.push("};".to_string());
+        code.push(format!("auto {read_token} = [&]() -> std::string {{"));
+        code.push(format!("\tint c = {read_byte}();"));
+        code.push(format!("\twhile (isspace(c)) c = {read_byte}();"));
+        code.push("\tstd::string s;".to_string());
+        code.push("\twhile (c != -1 && !isspace(c)) {".to_string());
+        // Copy the rest of the token in the buffer at once.
+        code.push("\t\ts.push_back(c);".to_string());
+        code.push(format!("\t\tsize_t i = {pos};"));
+        code.push(format!(
+            "\t\twhile (i < {len} && !isspace((unsigned char){buf}[i])) i++;"
+        ));
+        code.push(format!("\t\ts.append({buf} + {pos}, i - {pos});"));
+        code.push(format!("\t\t{pos} = i;"));
+        code.push(format!("\t\tc = {read_byte}();"));
+        code.push("\t}".to_string());
+        code.push("\treturn s;".to_string());
+        code.push("};".to_string());
+        // Out of range values saturate as std::cin does.
+        code.push(format!("auto {read_int} = [&]() -> int {{"));
+        code.push(format!("\tint c = {read_byte}();"));
+        code.push(format!("\twhile (isspace(c)) c = {read_byte}();"));
+        code.push("\tbool neg = c == '-';".to_string());
+        code.push(format!("\tif (neg) c = {read_byte}();"));
+        code.push("\tconst long long lim = (long long)INT_MAX + 1;".to_string());
+        code.push("\tlong long x = 0;".to_string());
+        code.push(format!(
+            "\twhile ('0' <= c && c <= '9') {{ x = std::min(x * 10 + (c - '0'), lim); c = {read_byte}(); }}"
+        ));
+        code.push("\treturn neg ? (int)-x : (int)std::min(x, lim - 1);".to_string());
+        code.push("};".to_string());
+        code.push(format!("auto {read_double} = [&]() -> double {{"));
+        code.push(format!("\treturn std::stod({read_token}());"));
+        code.push("};".to_string());
+        code
+    }
+    fn unit_type(bind: Bind, ast: &ast::UnitType) -> Code {
+        let ty = typing::unit_type(ast);
+        let code = format!("{ty} {bind} = {};", scan_unit_type(ast));
+        vec![code]
+    }
+    fn array(bind: Bind, ast: &ast::Array) -> Code {
+        let mut code = vec![];
+        let ty = typing::array(ast);
+        let n = Index(ast.1.expr.clone());
+        // std::array already has the length in its type.
+        if ast.1.const_num.is_some() {
+            code.push(format!("{ty} {bind};"));
+        } else {
+            code.push(format!("{ty} {bind}({n});"));
+        }
+        let x = new_var();
+        code.push(format!(
+            "for (auto& {x} : {bind}) {x} = {};",
+            scan_unit_type(&ast.0)
+        ));
+        code
+    }
+    fn matrix(bind: Bind, ast: &ast::Matrix) -> Result<Code, Error> {
+        let mut code = vec![];
+        let ty = format!("std::vector<{}>", typing::tuple_like(&ast.0));
+        let n = Index(ast.1.expr.clone());
+        code.push(format!("{ty} {bind}({n});"));
+        let x = new_var();
+        code.push(format!("for (auto& {x} : {bind}) {{"));
+
+        let tuple = new_var();
+        let inner_code = Self::tuple_like(tuple.clone(), &ast.0)?;
+        append_code(&mut code, "\t", inner_code);
+        code.push(format!("\t{x} = std::move({tuple});"));
+
+        code.push("}".to_string());
+        Ok(code)
+    }
+    fn tuple(bind: Bind, elems: Vec<(&ast::TupleElem, Bind)>) -> Result<Code, Error> {
+        let mut code = vec![];
+        let mut inner = vec![];
+        let n = elems.len();
+        for (_, e) in elems {
+            inner.push(e.0);
+        }
+        let inner = inner.join(", ");
+        if n == 1 {
+            code.push(format!("auto {bind} = {inner};"));
+        } else {
+            code.push(format!("auto {bind} = std::make_tuple({inner});"));
+        }
+        Ok(code)
+    }
+}
+
+fn scan_unit_type(ast: &ast::UnitType) -> String {
+    match ast {
+        ast::UnitType::Int => format!("{}()", shared_var("read_int")),
+        ast::UnitType::Int0 => format!("{}() - 1", shared_var("read_int")),
+        ast::UnitType::Float => format!("{}()", shared_var("read_double")),
+        ast::UnitType::Str => format!("{}()", shared_var("read_token")),
+    }
+}
@@ -1,4 +1,4 @@
-use super::cpp::typing;
+use super::cpp::{declare_static, static_len, typing};
 use super::*;
 
 pub struct CppStream;
@@ -11,9 +11,12 @@ impl stream::Lang for CppStream {
     fn array(bind: Bind, ast: &ast::Array) -> Code {
         let mut code = vec![];
         let ty = typing::array(&ast);
-        let n = Index(ast.1 .0.clone());
+        let n = Index(ast.1.expr.clone());
+        let fixed = ast.1.const_num.is_some();
         code.push(format!("{ty} {bind};"));
-        code.push(format!("{bind}.reserve({n});"));
+        if !fixed {
+            code.push(format!("{bind}.reserve({n});"));
+        }
 
         let k = new_var();
         code.push(format!("for (int {k}=0; {k}<{n}; ++{k}) {{"));
@@ -21,7 +24,11 @@ impl stream::Lang for CppStream {
         let mut inner_code = vec![];
         let unit_val = new_var();
         inner_code.append(&mut scan_unit_type(unit_val.clone(), &ast.0));
-        inner_code.push(format!("{bind}.push_back({unit_val});"));
+        if fixed {
+            inner_code.push(format!("{bind}[{k}] = {unit_val};"));
+        } else {
+            inner_code.push(format!("{bind}.push_back({unit_val});"));
+        }
         append_code(&mut code, "\t", inner_code);
 
         code.push(format!("}}"));
@@ -30,7 +37,7 @@ impl stream::Lang for CppStream {
     fn matrix(bind: Bind, ast: &ast::Matrix) -> Result<Code, Error> {
         let mut code = vec![];
         let ty = format!("std::vector<{}>", typing::tuple_like(&ast.0));
-        let n = Index(ast.1 .0.clone());
+        let n = Index(ast.1.expr.clone());
         code.push(format!("{ty} {bind};"));
         code.push(format!("{bind}.reserve({n});"));
         let k = new_var();
@@ -61,6 +68,92 @@ impl stream::Lang for CppStream {
     }
 }
 
+// Reads arrays and matrices with a capacity hint into static arrays like CppStatic.
+pub struct CppStreamStatic;
+impl stream::Lang for CppStreamStatic {
+    fn unit_type(bind: Bind, ast: &ast::UnitType) -> Code {
+        <CppStream as stream::Lang>::unit_type(bind, ast)
+    }
+    fn array(bind: Bind, ast: &ast::Array) -> Code {
+        <CppStream as stream::Lang>::array(bind, ast)
+    }
+    fn matrix(bind: Bind, ast: &ast::Matrix) -> Result<Code, Error> {
+        let cap = match ast.1.capacity {
+            Some(cap) => cap,
+            None => return <CppStream as stream::Lang>::matrix(bind, ast),
+        };
+        let mut code = vec![];
+        let n = Index(ast.1.expr.clone());
+        match &ast.0 {
+            ast::TupleLike::Array(row) if row.1.capacity.is_some() => {
+                let ty = typing::unit_type(&row.0);
+                declare_static(&bind, &ty, cap, row.1.capacity);
+            }
+            row => {
+                let ty = typing::tuple_like(row);
+                declare_static(&bind, &ty, cap, None);
+            }
+        }
+        code.append(&mut static_len(&bind, &n));
+
+        let k = new_var();
+        code.push(format!("for (int {k}=0; {k}<{bind}_len; ++{k}) {{"));
+
+        match &ast.0 {
+            ast::TupleLike::Array(row) if row.1.capacity.is_some() => {
+                let l = new_var();
+                let row_n = Index(row.1.expr.clone());
+                let mut inner_code = vec![];
+                let unit_val = new_var();
+                inner_code.append(&mut scan_unit_type(unit_val.clone(), &row.0));
+                inner_code.push(format!("{bind}[{k}][{l}] = {unit_val};"));
+                code.push(format!("\tassert({row_n} <= {bind}_row_cap);"));
+                code.push(format!("\tfor (int {l}=0; {l}<{row_n}; ++{l}) {{"));
+                append_code(&mut code, "\t\t", inner_code);
+                code.push("\t}".to_string());
+            }
+            row => {
+                let tuple = new_var();
+                let inner_code = <CppStream as stream::Lang>::tuple_like(tuple.clone(), row)?;
+                append_code(&mut code, "\t", inner_code);
+                code.push(format!("\t{bind}[{k}] = {tuple};"));
+            }
+        }
+
+        code.push("}".to_string());
+        Ok(code)
+    }
+    fn tuple(bind: Bind, elems: Vec<(&ast::TupleElem, Bind)>) -> Result<Code, Error> {
+        <CppStream as stream::Lang>::tuple(bind, elems)
+    }
+    // Only arrays at the top level become static.
+    fn tuple_like(bind: Bind, ast: &ast::TupleLike) -> Result<Code, Error> {
+        match ast {
+            ast::TupleLike::Array(x) if x.1.capacity.is_some() => {
+                let mut code = vec![];
+                let ty = typing::unit_type(&x.0);
+                let cap = x.1.capacity.unwrap();
+                let n = Index(x.1.expr.clone());
+                declare_static(&bind, &ty, cap, None);
+                code.append(&mut static_len(&bind, &n));
+
+                let k = new_var();
+                code.push(format!("for (int {k}=0; {k}<{bind}_len; ++{k}) {{"));
+
+                let mut inner_code = vec![];
+                let unit_val = new_var();
+                inner_code.append(&mut scan_unit_type(unit_val.clone(), &x.0));
+                inner_code.push(format!("{bind}[{k}] = {unit_val};"));
+                append_code(&mut code, "\t", inner_code);
+
+                code.push("}".to_string());
+                Ok(code)
+            }
+            _ => <CppStream as stream::Lang>::tuple_like(bind, ast),
+        }
+    }
+}
+
 fn scan_unit_type(bind: Bind, ast: &ast::UnitType) -> Code {
     let mut code = vec![];
     let ty = typing::unit_type(&ast);
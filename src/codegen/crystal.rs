@@ -0,0 +1,131 @@
+use super::*;
+
+// Unlike Ruby, empty arrays need the element type
+// so every binding is annotated with its type.
+pub struct Crystal;
+impl readline::Lang for Crystal {
+    fn read_line(bind: Bind) -> (Code, Index) {
+        let mut code = vec![];
+        code.push(format!("{bind} = read_line.split"));
+        let n = new_var();
+        code.push(format!("{n} = {bind}.size"));
+        (code, Index(n.0))
+    }
+    fn unit_type(bind: Bind, ast: &ast::UnitType, source: Slice) -> Code {
+        let Slice(xs, range) = source;
+        let i = range.0;
+        let ty = typing::unit_type(ast);
+        let v = format!("{xs}[{i}]");
+        let code = format!("{bind} : {ty} = {}", unit_type_convert(ast, &v));
+        vec![code]
+    }
+    fn array(bind: Bind, ast: &ast::Array, source: Slice) -> Code {
+        let Slice(xs, range) = source;
+        let i = range.0;
+        let j = range.1;
+        let ty = typing::array(ast);
+        let v = format!("{xs}[{i}...{j}]");
+        let rhs = match &ast.0 {
+            ast::UnitType::Int => format!("{v}.map(&.to_i)"),
+            ast::UnitType::Int0 => format!("{v}.map {{ |x| x.to_i - 1 }}"),
+            ast::UnitType::Float => format!("{v}.map(&.to_f)"),
+            ast::UnitType::Str => v,
+        };
+        let code = format!("{bind} : {ty} = {rhs}");
+        vec![code]
+    }
+    fn matrix(bind: Bind, ast: &ast::Matrix) -> Result<Code, Error> {
+        let mut code = vec![];
+        let ty = typing::tuple_like(&ast.0);
+        let n = Index(ast.1.expr.clone());
+        code.push(format!("{bind} = [] of {ty}"));
+        code.push(format!("({n}).times do"));
+
+        let mut inner_code = vec![];
+        let line = new_var();
+        let (mut read_line, m) = Self::read_line(line.clone());
+        inner_code.append(&mut read_line);
+
+        let t = new_var();
+        let slice = Slice(line, Range(Index::zero(), m));
+        inner_code.append(&mut Self::tuple_like(t.clone(), &ast.0, slice)?);
+        inner_code.push(format!("{bind} << {t}"));
+
+        append_code(&mut code, "  ", inner_code);
+        code.push("end".to_string());
+        Ok(code)
+    }
+    fn tuple(bind: Bind, elems: Vec<(&ast::TupleElem, Bind)>) -> Result<Code, Error> {
+        let mut inner = vec![];
+        let n = elems.len();
+        for (_, e) in elems {
+            inner.push(e.0);
+        }
+        let inner = inner.join(", ");
+        let code = if n == 1 {
+            format!("{bind} = {inner}")
+        } else {
+            format!("{bind} = {{{inner}}}")
+        };
+        Ok(vec![code])
+    }
+}
+fn unit_type_convert(ty: &ast::UnitType, v: &str) -> String {
+    match ty {
+        ast::UnitType::Int => {
+            format!("{v}.to_i")
+        }
+        ast::UnitType::Int0 => {
+            format!("{v}.to_i - 1")
+        }
+        ast::UnitType::Float => {
+            format!("{v}.to_f")
+        }
+        ast::UnitType::Str => v.to_string(),
+    }
+}
+type Type = String;
+mod typing {
+    use super::*;
+    pub fn unit_type(ty: &ast::UnitType) -> Type {
+        match ty {
+            ast::UnitType::Int => "Int32".to_string(),
+            ast::UnitType::Int0 => "Int32".to_string(),
+            ast::UnitType::Float => "Float64".to_string(),
+            ast::UnitType::Str => "String".to_string(),
+        }
+    }
+    pub fn array(ty: &ast::Array) -> Type {
+        let inner = unit_type(&ty.0);
+        format!("Array({inner})")
+    }
+    pub fn list(ty: &ast::List) -> Type {
+        let inner = unit_type(&ty.0);
+        format!("Array({inner})")
+    }
+    pub fn tuple(ty: &ast::Tuple) -> Type {
+        let mut inner = vec![];
+        let n = ty.0.len();
+        for e in &ty.0 {
+            let ty = match e {
+                TupleElem::Array(x) => array(x),
+                TupleElem::List(x) => list(x),
+                TupleElem::UnitType(x) => unit_type(x),
+            };
+            inner.push(ty);
+        }
+        let inner = inner.join(", ");
+        if n == 1 {
+            inner.to_string()
+        } else {
+            format!("Tuple({inner})")
+        }
+    }
+    pub fn tuple_like(ty: &ast::TupleLike) -> Type {
+        match ty {
+            ast::TupleLike::Array(x) => array(x),
+            ast::TupleLike::List(x) => list(x),
+            ast::TupleLike::Tuple(x) => tuple(x),
+        }
+    }
+}
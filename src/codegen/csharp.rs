@@ -42,7 +42,7 @@ impl readline::Lang for CSharp {
         let mut code = vec![];
         let ty = format!("List<{}>", typing::tuple_like(&ast.0));
         let n = &ast.1;
-        let n = Index(n.0.clone());
+        let n = Index(n.expr.clone());
         code.push(format!("var {bind} = new {ty}();"));
         let k = new_var();
         code.push(format!("for (int {k}=0; {k}<{n}; {k}++) {{"));
@@ -70,7 +70,7 @@ impl readline::Lang for CSharp {
         Ok(vec![code])
     }
 }
-fn unit_type_convert(ty: &ast::UnitType, v: &str) -> String {
+pub fn unit_type_convert(ty: &ast::UnitType, v: &str) -> String {
     match ty {
         ast::UnitType::Int => {
             format!("Convert.ToInt32({v})")
@@ -86,7 +86,7 @@ fn unit_type_convert(ty: &ast::UnitType, v: &str) -> String {
 }
 
 type Type = String;
-mod typing {
+pub mod typing {
     use super::*;
     pub fn unit_type(ty: &ast::UnitType) -> Type {
         match ty {
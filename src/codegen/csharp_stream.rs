@@ -0,0 +1,115 @@
+use super::csharp::typing;
+use super::*;
+
+pub struct CSharpStream;
+impl stream::Lang for CSharpStream {
+    // Console.ReadLine and string.Split are too slow for large inputs
+    // so we read raw bytes from the stdin stream and parse them by hand.
+    fn prelude() -> Code {
+        let read_byte = shared_var("read_byte");
+        let read_token = shared_var("read_token");
+        let read_int = shared_var("read_int");
+        let mut code = vec![];
+        let input = new_var();
+        let buf = new_var();
+        let len = new_var();
+        let ptr = new_var();
+        code.push(format!("var {input} = Console.OpenStandardInput();"));
+        code.push(format!("var {buf} = new byte[1 << 16];"));
+        code.push(format!("int {len} = 0, {ptr} = 0;"));
+        code.push(format!("Func<int> {read_byte} = () => {{"));
+        code.push(format!(
+            "\tif ({ptr} == {len}) {{ {len} = {input}.Read({buf}, 0, {buf}.Length); {ptr} = 0; }}"
+        ));
+        code.push(format!("\treturn {len} <= 0 ? -1 : {buf}[{ptr}++];"));
+        code.push("};".to_string());
+
+        let c = new_var();
+        let sb = new_var();
+        code.push(format!("Func<string> {read_token} = () => {{"));
+        code.push(format!("\tint {c} = {read_byte}();"));
+        code.push(format!(
+            "\twhile ({c} != -1 && {c} <= ' ') {c} = {read_byte}();"
+        ));
+        code.push(format!("\tvar {sb} = new System.Text.StringBuilder();"));
+        code.push(format!(
+            "\twhile ({c} > ' ') {{ {sb}.Append((char){c}); {c} = {read_byte}(); }}"
+        ));
+        code.push(format!("\treturn {sb}.ToString();"));
+        code.push("};".to_string());
+
+        let c = new_var();
+        let neg = new_var();
+        let x = new_var();
+        code.push(format!("Func<int> {read_int} = () => {{"));
+        code.push(format!("\tint {c} = {read_byte}();"));
+        code.push(format!(
+            "\twhile ({c} != -1 && {c} <= ' ') {c} = {read_byte}();"
+        ));
+        code.push(format!("\tbool {neg} = {c} == '-';"));
+        code.push(format!("\tif ({neg}) {c} = {read_byte}();"));
+        code.push(format!("\tint {x} = 0;"));
+        code.push(format!(
+            "\twhile ('0' <= {c} && {c} <= '9') {{ {x} = {x} * 10 + ({c} - '0'); {c} = {read_byte}(); }}"
+        ));
+        code.push(format!("\treturn {neg} ? -{x} : {x};"));
+        code.push("};".to_string());
+        code
+    }
+    fn unit_type(bind: Bind, ast: &ast::UnitType) -> Code {
+        let ty = typing::unit_type(ast);
+        let code = format!("{ty} {bind} = {};", scan_unit_type(ast));
+        vec![code]
+    }
+    fn array(bind: Bind, ast: &ast::Array) -> Code {
+        let mut code = vec![];
+        let ty = typing::array(ast);
+        let n = Index(ast.1.expr.clone());
+        code.push(format!("var {bind} = new {ty}({n});"));
+        let k = new_var();
+        code.push(format!("for (int {k}=0; {k}<{n}; {k}++) {{"));
+        code.push(format!("\t{bind}.Add({});", scan_unit_type(&ast.0)));
+        code.push("}".to_string());
+        code
+    }
+    fn matrix(bind: Bind, ast: &ast::Matrix) -> Result<Code, Error> {
+        let mut code = vec![];
+        let ty = format!("List<{}>", typing::tuple_like(&ast.0));
+        let n = Index(ast.1.expr.clone());
+        code.push(format!("var {bind} = new {ty}({n});"));
+        let k = new_var();
+        code.push(format!("for (int {k}=0; {k}<{n}; {k}++) {{"));
+
+        let mut inner_code = vec![];
+        let tuple = new_var();
+        inner_code.append(&mut Self::tuple_like(tuple.clone(), &ast.0)?);
+        inner_code.push(format!("{bind}.Add({tuple});"));
+        append_code(&mut code, "\t", inner_code);
+
+        code.push("}".to_string());
+        Ok(code)
+    }
+    fn tuple(bind: Bind, elems: Vec<(&ast::TupleElem, Bind)>) -> Result<Code, Error> {
+        let mut inner = vec![];
+        for (_, e) in elems {
+            inner.push(e.0);
+        }
+        let inner = inner.join(",");
+        let code = format!("var {bind} = ({inner});");
+        Ok(vec![code])
+    }
+}
+fn scan_unit_type(ty: &ast::UnitType) -> String {
+    let read_token = shared_var("read_token");
+    let read_int = shared_var("read_int");
+    match ty {
+        ast::UnitType::Int => format!("{read_int}()"),
+        ast::UnitType::Int0 => format!("({read_int}()-1)"),
+        ast::UnitType::Float => {
+            format!(
+                "double.Parse({read_token}(), System.Globalization.CultureInfo.InvariantCulture)"
+            )
+        }
+        ast::UnitType::Str => format!("{read_token}()"),
+    }
+}
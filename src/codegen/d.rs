@@ -0,0 +1,133 @@
+use super::*;
+
+pub struct D;
+impl readline::Lang for D {
+    fn read_line(bind: Bind) -> (Code, Index) {
+        let mut code = vec![];
+        code.push(format!("auto {bind} = readln.split;"));
+        let n = new_var();
+        code.push(format!("auto {n} = {bind}.length;"));
+        (code, Index(n.0))
+    }
+    fn unit_type(bind: Bind, ast: &ast::UnitType, source: Slice) -> Code {
+        let Slice(xs, range) = source;
+        let i = range.0;
+        let ty = typing::unit_type(ast);
+        let v = format!("{xs}[{i}]");
+        let code = format!("{ty} {bind} = {};", unit_type_convert(ast, &v));
+        vec![code]
+    }
+    fn array(bind: Bind, ast: &ast::Array, source: Slice) -> Code {
+        let Slice(xs, range) = source;
+        let i = range.0;
+        let j = range.1;
+        let ty = typing::array(ast);
+        let v = format!("{xs}[{i} .. {j}]");
+        // A static array is initialized by copying from the dynamic array.
+        let rhs = match &ast.0 {
+            ast::UnitType::Int0 => format!("{v}.map!(x => x.to!int - 1).array"),
+            ast::UnitType::Str => v,
+            x => format!("{v}.to!({}[])", typing::unit_type(x)),
+        };
+        let code = format!("{ty} {bind} = {rhs};");
+        vec![code]
+    }
+    fn matrix(bind: Bind, ast: &ast::Matrix) -> Result<Code, Error> {
+        let mut code = vec![];
+        let ty = typing::tuple_like(&ast.0);
+        let n = Index(ast.1.expr.clone());
+        code.push(format!("{ty}[] {bind};"));
+        code.push(format!("{bind}.reserve({n});"));
+        code.push(format!("foreach (_; 0 .. {n}) {{"));
+
+        let mut inner_code = vec![];
+        let line = new_var();
+        let (mut read_line, m) = Self::read_line(line.clone());
+        inner_code.append(&mut read_line);
+
+        let t = new_var();
+        let slice = Slice(line, Range(Index::zero(), m));
+        inner_code.append(&mut Self::tuple_like(t.clone(), &ast.0, slice)?);
+        inner_code.push(format!("{bind} ~= {t};"));
+
+        append_code(&mut code, "\t", inner_code);
+        code.push("}".to_string());
+        Ok(code)
+    }
+    fn tuple(bind: Bind, elems: Vec<(&ast::TupleElem, Bind)>) -> Result<Code, Error> {
+        let mut inner = vec![];
+        let n = elems.len();
+        for (_, e) in elems {
+            inner.push(e.0);
+        }
+        let inner = inner.join(", ");
+        let code = if n == 1 {
+            format!("auto {bind} = {inner};")
+        } else {
+            format!("auto {bind} = tuple({inner});")
+        };
+        Ok(vec![code])
+    }
+}
+fn unit_type_convert(ty: &ast::UnitType, v: &str) -> String {
+    match ty {
+        ast::UnitType::Int => {
+            format!("{v}.to!int")
+        }
+        ast::UnitType::Int0 => {
+            format!("{v}.to!int - 1")
+        }
+        ast::UnitType::Float => {
+            format!("{v}.to!double")
+        }
+        ast::UnitType::Str => v.to_string(),
+    }
+}
+type Type = String;
+mod typing {
+    use super::*;
+    pub fn unit_type(ty: &ast::UnitType) -> Type {
+        match ty {
+            ast::UnitType::Int => "int".to_string(),
+            ast::UnitType::Int0 => "int".to_string(),
+            ast::UnitType::Float => "double".to_string(),
+            ast::UnitType::Str => "string".to_string(),
+        }
+    }
+    pub fn array(ty: &ast::Array) -> Type {
+        let inner = unit_type(&ty.0);
+        match ty.1.const_num {
+            Some(ast::ConstNum(n)) => format!("{inner}[{n}]"),
+            None => format!("{inner}[]"),
+        }
+    }
+    pub fn list(ty: &ast::List) -> Type {
+        let inner = unit_type(&ty.0);
+        format!("{inner}[]")
+    }
+    pub fn tuple(ty: &ast::Tuple) -> Type {
+        let mut inner = vec![];
+        let n = ty.0.len();
+        for e in &ty.0 {
+            let ty = match e {
+                TupleElem::Array(x) => array(x),
+                TupleElem::List(x) => list(x),
+                TupleElem::UnitType(x) => unit_type(x),
+            };
+            inner.push(ty);
+        }
+        let inner = inner.join(", ");
+        if n == 1 {
+            inner.to_string()
+        } else {
+            format!("Tuple!({inner})")
+        }
+    }
+    pub fn tuple_like(ty: &ast::TupleLike) -> Type {
+        match ty {
+            ast::TupleLike::Array(x) => array(x),
+            ast::TupleLike::List(x) => list(x),
+            ast::TupleLike::Tuple(x) => tuple(x),
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use super::*;
+
+// The tokens are never mutated but rebound to the rest
+// every time some of them are consumed.
+// Matrices thread the tokens through Enum.map_reduce.
+pub struct Elixir;
+impl stream::Lang for Elixir {
+    fn prelude() -> Code {
+        vec!["tokens = IO.read(:stdio, :eof) |> String.split()".to_string()]
+    }
+    fn unit_type(bind: Bind, ast: &ast::UnitType) -> Code {
+        let mut code = vec![];
+        let x = new_var();
+        code.push(format!("[{x} | tokens] = tokens"));
+        code.push(format!("{bind} = {}", unit_type_convert(ast, &x.0)));
+        code
+    }
+    fn array(bind: Bind, ast: &ast::Array) -> Code {
+        let mut code = vec![];
+        let n = Index(ast.1.expr.clone());
+        let xs = new_var();
+        code.push(format!("{{{xs}, tokens}} = Enum.split(tokens, {n})"));
+        let rhs = match &ast.0 {
+            ast::UnitType::Str => format!("{xs}"),
+            x => format!("Enum.map({xs}, fn x -> {} end)", unit_type_convert(x, "x")),
+        };
+        code.push(format!("{bind} = {rhs}"));
+        code
+    }
+    fn matrix(bind: Bind, ast: &ast::Matrix) -> Result<Code, Error> {
+        let mut code = vec![];
+        let n = Index(ast.1.expr.clone());
+        code.push(format!(
+            "{{{bind}, tokens}} = Enum.map_reduce(1..{n}//1, tokens, fn _, tokens ->"
+        ));
+
+        let mut inner_code = vec![];
+        let t = new_var();
+        inner_code.append(&mut Self::tuple_like(t.clone(), &ast.0)?);
+        inner_code.push(format!("{{{t}, tokens}}"));
+
+        append_code(&mut code, "  ", inner_code);
+        code.push("end)".to_string());
+        Ok(code)
+    }
+    fn tuple(bind: Bind, elems: Vec<(&ast::TupleElem, Bind)>) -> Result<Code, Error> {
+        let mut inner = vec![];
+        let n = elems.len();
+        for (_, e) in elems {
+            inner.push(e.0);
+        }
+        let inner = inner.join(", ");
+        let code = if n == 1 {
+            format!("{bind} = {inner}")
+        } else {
+            format!("{bind} = {{{inner}}}")
+        };
+        Ok(vec![code])
+    }
+}
+fn unit_type_convert(ty: &ast::UnitType, v: &str) -> String {
+    match ty {
+        ast::UnitType::Int => {
+            format!("String.to_integer({v})")
+        }
+        ast::UnitType::Int0 => {
+            format!("String.to_integer({v}) - 1")
+        }
+        ast::UnitType::Float => {
+            format!("elem(Float.parse({v}), 0)")
+        }
+        ast::UnitType::Str => v.to_string(),
+    }
+}
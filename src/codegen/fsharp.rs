@@ -0,0 +1,64 @@
+use super::*;
+
+// The numbers are parsed the same way as C#.
+pub struct FSharp;
+impl readline::Lang for FSharp {
+    fn read_line(bind: Bind) -> (Code, Index) {
+        let mut code = vec![];
+        code.push(format!("let {bind} = stdin.ReadLine().Split()"));
+        let n = new_var();
+        code.push(format!("let {n} = {bind}.Length"));
+        (code, Index(n.0))
+    }
+    fn unit_type(bind: Bind, ast: &ast::UnitType, source: Slice) -> Code {
+        let Slice(xs, range) = source;
+        let i = range.0;
+        let v = format!("{xs}.[{i}]");
+        let code = format!("let {bind} = {}", csharp::unit_type_convert(ast, &v));
+        vec![code]
+    }
+    fn array(bind: Bind, ast: &ast::Array, source: Slice) -> Code {
+        let Slice(xs, range) = source;
+        let i = range.0;
+        let n = Index(ast.1.expr.clone());
+        let k = new_var();
+        let v = format!("{xs}.[{i} + {k}]");
+        let code = format!(
+            "let {bind} = Array.init ({n}) (fun {k} -> {})",
+            csharp::unit_type_convert(&ast.0, &v)
+        );
+        vec![code]
+    }
+    fn matrix(bind: Bind, ast: &ast::Matrix) -> Result<Code, Error> {
+        let mut code = vec![];
+        let n = Index(ast.1.expr.clone());
+        code.push(format!("let {bind} = Array.init ({n}) (fun _ ->"));
+
+        let mut inner_code = vec![];
+        let line = new_var();
+        let (mut read_line, m) = Self::read_line(line.clone());
+        inner_code.append(&mut read_line);
+
+        let t = new_var();
+        let slice = Slice(line, Range(Index::zero(), m));
+        inner_code.append(&mut Self::tuple_like(t.clone(), &ast.0, slice)?);
+        inner_code.push(format!("{t})"));
+
+        append_code(&mut code, "    ", inner_code);
+        Ok(code)
+    }
+    fn tuple(bind: Bind, elems: Vec<(&ast::TupleElem, Bind)>) -> Result<Code, Error> {
+        let mut inner = vec![];
+        let n = elems.len();
+        for (_, e) in elems {
+            inner.push(e.0);
+        }
+        let inner = inner.join(", ");
+        let code = if n == 1 {
+            format!("let {bind} = {inner}")
+        } else {
+            format!("let {bind} = ({inner})")
+        };
+        Ok(vec![code])
+    }
+}
@@ -10,14 +10,24 @@ impl stream::Lang for GoStream {
     fn array(bind: Bind, ast: &ast::Array) -> Code {
         let mut code = vec![];
         let inner_ty = typing::unit_type(&ast.0);
-        let n = Index(ast.1 .0.clone());
-        code.push(format!("{bind} := make([]{inner_ty}, 0, {n})"));
-        code.push(format!("for i := 0; i < {n}; i++ {{"));
+        let n = Index(ast.1.expr.clone());
+        let fixed = ast.1.const_num.is_some();
+        if fixed {
+            code.push(format!("var {bind} {}", typing::array(ast)));
+            code.push(format!("for i := range {bind} {{"));
+        } else {
+            code.push(format!("{bind} := make([]{inner_ty}, 0, {n})"));
+            code.push(format!("for i := 0; i < {n}; i++ {{"));
+        }
 
         let mut inner_code = vec![];
         let v = new_var();
         inner_code.append(&mut scan_unit_type(v.clone(), &ast.0));
-        inner_code.push(format!("{bind} = append({bind}, {v})"));
+        if fixed {
+            inner_code.push(format!("{bind}[i] = {v}"));
+        } else {
+            inner_code.push(format!("{bind} = append({bind}, {v})"));
+        }
 
         append_code(&mut code, "\t", inner_code);
         code.push(format!("}}"));
@@ -25,7 +35,7 @@ impl stream::Lang for GoStream {
     }
     fn matrix(bind: Bind, ast: &ast::Matrix) -> Result<Code, Error> {
         let mut code = vec![];
-        let n = Index(ast.1 .0.clone());
+        let n = Index(ast.1.expr.clone());
         let ty = format!("[]{}", typing::tuple_like(&ast.0)?);
         code.push(format!("{bind} := make({ty}, 0, {n})"));
         let k = new_var();
@@ -83,7 +93,10 @@ pub mod typing {
     }
     pub fn array(ty: &ast::Array) -> Type {
         let inner = unit_type(&ty.0);
-        format!("[]{inner}")
+        match ty.1.const_num {
+            Some(ast::ConstNum(n)) => format!("[{n}]{inner}"),
+            None => format!("[]{inner}"),
+        }
     }
     pub fn list(ty: &ast::List) -> Type {
         let inner = unit_type(&ty.0);
@@ -0,0 +1,69 @@
+use super::*;
+
+pub struct Haskell;
+impl stream::Lang for Haskell {
+    fn prelude() -> Code {
+        vec![
+            "input <- newIORef . BS.words =<< BS.getContents".to_string(),
+            "let readToken = atomicModifyIORef' input (\\(t:ts) -> (ts, t))".to_string(),
+            "let readInt = fst . fromJust . BS.readInt <$> readToken".to_string(),
+            "let readDouble = read . BS.unpack <$> readToken :: IO Double".to_string(),
+        ]
+    }
+    fn unit_type(bind: Bind, ast: &ast::UnitType) -> Code {
+        let code = format!("{bind} <- {}", scan_unit_type(ast));
+        vec![code]
+    }
+    fn array(bind: Bind, ast: &ast::Array) -> Code {
+        let n = Index(ast.1.expr.clone());
+        let code = format!("{bind} <- replicateM ({n}) ({})", scan_unit_type(&ast.0));
+        vec![code]
+    }
+    fn matrix(bind: Bind, ast: &ast::Matrix) -> Result<Code, Error> {
+        let mut code = vec![];
+        let n = Index(ast.1.expr.clone());
+        code.push(format!("{bind} <- replicateM ({n}) $ do"));
+
+        let mut inner_code = vec![];
+        let t = new_var();
+        inner_code.append(&mut Self::tuple_like(t.clone(), &ast.0)?);
+        inner_code.push(format!("return {t}"));
+
+        append_code(&mut code, "  ", inner_code);
+        Ok(code)
+    }
+    fn tuple(bind: Bind, elems: Vec<(&ast::TupleElem, Bind)>) -> Result<Code, Error> {
+        let mut inner = vec![];
+        let n = elems.len();
+        for (_, e) in elems {
+            inner.push(e.0);
+        }
+        let inner = inner.join(", ");
+        let code = if n == 1 {
+            format!("let {bind} = {inner}")
+        } else {
+            format!("let {bind} = ({inner})")
+        };
+        Ok(vec![code])
+    }
+}
+fn scan_unit_type(ty: &ast::UnitType) -> String {
+    match ty {
+        ast::UnitType::Int => "readInt".to_string(),
+        ast::UnitType::Int0 => "subtract 1 <$> readInt".to_string(),
+        ast::UnitType::Float => "readDouble".to_string(),
+        ast::UnitType::Str => "BS.unpack <$> readToken".to_string(),
+    }
+}
+
+// The bindings must be indented to be placed in the do-block of main.
+pub fn emit(root: ast::Root) -> anyhow::Result<String> {
+    let code = stream::emit::<Haskell>(root)?;
+    let mut out = vec![];
+    append_code(
+        &mut out,
+        "  ",
+        code.lines().map(|x| x.to_string()).collect(),
+    );
+    Ok(out.join("\n"))
+}
@@ -21,15 +21,14 @@ impl readline::Lang for Java {
         let mut code = vec![];
         let Slice(xs, range) = source;
         let i = range.0;
-        let j = range.1;
-        let ty = typing::array(&ast);
-        code.push(format!("var {bind} = new {ty}();"));
+        let ty = typing::primitive_type(&ast.0);
+        let n = Index(ast.1.expr.clone());
+        code.push(format!("var {bind} = new {ty}[{n}];"));
         let k = new_var();
-        code.push(format!("for (int {k}={i}; {k}<{j}; {k}++) {{"));
-        let x = new_var();
-        let v = format!("{xs}[{k}]");
+        code.push(format!("for (int {k}=0; {k}<{n}; {k}++) {{"));
+        let v = format!("{xs}[{i}+{k}]");
         let v = unit_type_convert(&ast.0, &v);
-        code.push(format!("\tvar {x} = {v}; {bind}.add({x});"));
+        code.push(format!("\t{bind}[{k}] = {v};"));
         code.push(format!("}}"));
         code
     }
@@ -37,7 +36,7 @@ impl readline::Lang for Java {
         let mut code = vec![];
         let ty = format!("ArrayList<{}>", typing::tuple_like(&ast.0)?);
         let n = &ast.1;
-        let n = Index(n.0.clone());
+        let n = Index(n.expr.clone());
         code.push(format!("var {bind} = new {ty}();"));
         let k = new_var();
         code.push(format!("for (int {k}=0; {k}<{n}; {k}++) {{"));
@@ -77,13 +76,23 @@ pub mod typing {
             ast::UnitType::Str => "String".to_string(),
         }
     }
+    // Arrays are always allocated with the known length
+    // so we can use primitive arrays to avoid boxing.
+    pub fn primitive_type(ty: &ast::UnitType) -> Type {
+        match ty {
+            ast::UnitType::Int => "int".to_string(),
+            ast::UnitType::Int0 => "int".to_string(),
+            ast::UnitType::Float => "double".to_string(),
+            ast::UnitType::Str => "String".to_string(),
+        }
+    }
     pub fn array(ty: &ast::Array) -> Type {
-        let inner = unit_type(&ty.0);
-        format!("ArrayList<{inner}>")
+        let inner = primitive_type(&ty.0);
+        format!("{inner}[]")
     }
     pub fn list(ty: &ast::List) -> Type {
-        let inner = unit_type(&ty.0);
-        format!("ArrayList<{inner}>")
+        let inner = primitive_type(&ty.0);
+        format!("{inner}[]")
     }
     pub fn tuple_like(ty: &ast::TupleLike) -> Result<Type, Error> {
         match ty {
@@ -11,16 +11,16 @@ impl stream::Lang for JavaStream {
     }
     fn array(bind: Bind, ast: &ast::Array) -> Code {
         let mut code = vec![];
-        let n = Index(ast.1 .0.clone());
-        let ty = typing::array(&ast);
-        code.push(format!("var {bind} = new {ty}();"));
+        let n = Index(ast.1.expr.clone());
+        let ty = typing::primitive_type(&ast.0);
+        code.push(format!("var {bind} = new {ty}[{n}];"));
         let k = new_var();
         code.push(format!("for (int {k}=0; {k}<{n}; {k}++) {{"));
 
         let mut inner_code = vec![];
         let x = new_var();
         inner_code.append(&mut scan_unit_type(x.clone(), &ast.0));
-        inner_code.push(format!("{bind}.add({x});"));
+        inner_code.push(format!("{bind}[{k}] = {x};"));
         append_code(&mut code, "\t", inner_code);
 
         code.push(format!("}}"));
@@ -28,7 +28,7 @@ impl stream::Lang for JavaStream {
     }
     fn matrix(bind: Bind, ast: &ast::Matrix) -> Result<Code, Error> {
         let mut code = vec![];
-        let n = Index(ast.1 .0.clone());
+        let n = Index(ast.1.expr.clone());
         let ty = format!("ArrayList<{}>", typing::tuple_like(&ast.0)?);
         code.push(format!("var {bind} = new {ty}();"));
         let k = new_var();
@@ -0,0 +1,188 @@
+use super::*;
+
+// Julia is 1-indexed so the indices computed by the caller
+// are shifted by one when the tokens are accessed.
+pub struct Julia;
+impl readline::Lang for Julia {
+    fn read_line(bind: Bind) -> (Code, Index) {
+        let mut code = vec![];
+        code.push(format!("{bind} = split(readline())"));
+        let n = new_var();
+        code.push(format!("{n} = length({bind})"));
+        (code, Index(n.0))
+    }
+    fn unit_type(bind: Bind, ast: &ast::UnitType, source: Slice) -> Code {
+        let Slice(xs, range) = source;
+        let i = range.0;
+        let v = format!("{xs}[{i}+1]");
+        let code = format!("{bind} = {}", unit_type_convert(ast, &v));
+        vec![code]
+    }
+    fn array(bind: Bind, ast: &ast::Array, source: Slice) -> Code {
+        let Slice(xs, range) = source;
+        let i = range.0;
+        let j = range.1;
+        let v = format!("{xs}[{i}+1:{j}]");
+        let code = format!("{bind} = {}", unit_type_broadcast(&ast.0, &v));
+        vec![code]
+    }
+    fn matrix(bind: Bind, ast: &ast::Matrix) -> Result<Code, Error> {
+        let mut code = vec![];
+        let ty = typing::tuple_like(&ast.0);
+        let n = Index(ast.1.expr.clone());
+        code.push(format!("{bind} = {ty}[]"));
+        code.push(format!("sizehint!({bind}, {n})"));
+        code.push(format!("for _ in 1:{n}"));
+
+        let mut inner_code = vec![];
+        let line = new_var();
+        let (mut read_line, m) = Self::read_line(line.clone());
+        inner_code.append(&mut read_line);
+
+        let t = new_var();
+        let slice = Slice(line, Range(Index::zero(), m));
+        inner_code.append(&mut Self::tuple_like(t.clone(), &ast.0, slice)?);
+        inner_code.push(format!("push!({bind}, {t})"));
+
+        append_code(&mut code, "    ", inner_code);
+        code.push("end".to_string());
+        Ok(code)
+    }
+    fn tuple(bind: Bind, elems: Vec<(&ast::TupleElem, Bind)>) -> Result<Code, Error> {
+        let mut inner = vec![];
+        let n = elems.len();
+        for (_, e) in elems {
+            inner.push(e.0);
+        }
+        let inner = inner.join(", ");
+        let code = if n == 1 {
+            format!("{bind} = {inner}")
+        } else {
+            format!("{bind} = ({inner})")
+        };
+        Ok(vec![code])
+    }
+}
+
+// Same as Julia but rectangular matrices like [[int; m]; n]
+// are read into a 2-D Matrix instead of a vector of vectors.
+pub struct JuliaDense;
+impl readline::Lang for JuliaDense {
+    fn read_line(bind: Bind) -> (Code, Index) {
+        <Julia as readline::Lang>::read_line(bind)
+    }
+    fn unit_type(bind: Bind, ast: &ast::UnitType, source: Slice) -> Code {
+        <Julia as readline::Lang>::unit_type(bind, ast, source)
+    }
+    fn array(bind: Bind, ast: &ast::Array, source: Slice) -> Code {
+        <Julia as readline::Lang>::array(bind, ast, source)
+    }
+    fn matrix(bind: Bind, ast: &ast::Matrix) -> Result<Code, Error> {
+        let row = match &ast.0 {
+            ast::TupleLike::Array(x) => x,
+            _ => return <Julia as readline::Lang>::matrix(bind, ast),
+        };
+        let mut code = vec![];
+        let ty = typing::unit_type(&row.0);
+        let n = Index(ast.1.expr.clone());
+        let m = Index(row.1.expr.clone());
+        code.push(format!("{bind} = Matrix{{{ty}}}(undef, {n}, {m})"));
+        let k = new_var();
+        code.push(format!("for {k} in 1:{n}"));
+
+        let mut inner_code = vec![];
+        let line = new_var();
+        let (mut read_line, len) = Self::read_line(line.clone());
+        inner_code.append(&mut read_line);
+
+        let xs = new_var();
+        let slice = Slice(line, Range(Index::zero(), len));
+        inner_code.append(&mut Self::array(xs.clone(), row, slice));
+        inner_code.push(format!("{bind}[{k}, :] = {xs}"));
+
+        append_code(&mut code, "    ", inner_code);
+        code.push("end".to_string());
+        Ok(code)
+    }
+    fn tuple(bind: Bind, elems: Vec<(&ast::TupleElem, Bind)>) -> Result<Code, Error> {
+        <Julia as readline::Lang>::tuple(bind, elems)
+    }
+}
+
+fn unit_type_convert(ty: &ast::UnitType, v: &str) -> String {
+    match ty {
+        ast::UnitType::Int => {
+            format!("parse(Int, {v})")
+        }
+        ast::UnitType::Int0 => {
+            format!("(parse(Int, {v}) - 1)")
+        }
+        ast::UnitType::Float => {
+            format!("parse(Float64, {v})")
+        }
+        ast::UnitType::Str => {
+            format!("String({v})")
+        }
+    }
+}
+fn unit_type_broadcast(ty: &ast::UnitType, v: &str) -> String {
+    match ty {
+        ast::UnitType::Int => {
+            format!("parse.(Int, {v})")
+        }
+        ast::UnitType::Int0 => {
+            format!("(parse.(Int, {v}) .- 1)")
+        }
+        ast::UnitType::Float => {
+            format!("parse.(Float64, {v})")
+        }
+        ast::UnitType::Str => {
+            format!("String.({v})")
+        }
+    }
+}
+type Type = String;
+mod typing {
+    use super::*;
+    pub fn unit_type(ty: &ast::UnitType) -> Type {
+        match ty {
+            ast::UnitType::Int => "Int".to_string(),
+            ast::UnitType::Int0 => "Int".to_string(),
+            ast::UnitType::Float => "Float64".to_string(),
+            ast::UnitType::Str => "String".to_string(),
+        }
+    }
+    pub fn array(ty: &ast::Array) -> Type {
+        let inner = unit_type(&ty.0);
+        format!("Vector{{{inner}}}")
+    }
+    pub fn list(ty: &ast::List) -> Type {
+        let inner = unit_type(&ty.0);
+        format!("Vector{{{inner}}}")
+    }
+    pub fn tuple(ty: &ast::Tuple) -> Type {
+        let mut inner = vec![];
+        let n = ty.0.len();
+        for e in &ty.0 {
+            let ty = match e {
+                TupleElem::Array(x) => array(x),
+                TupleElem::List(x) => list(x),
+                TupleElem::UnitType(x) => unit_type(x),
+            };
+            inner.push(ty);
+        }
+        let inner = inner.join(", ");
+        if n == 1 {
+            inner.to_string()
+        } else {
+            format!("Tuple{{{inner}}}")
+        }
+    }
+    pub fn tuple_like(ty: &ast::TupleLike) -> Type {
+        match ty {
+            ast::TupleLike::Array(x) => array(x),
+            ast::TupleLike::List(x) => list(x),
+            ast::TupleLike::Tuple(x) => tuple(x),
+        }
+    }
+}
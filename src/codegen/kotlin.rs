@@ -21,23 +21,28 @@ impl readline::Lang for Kotlin {
         let mut code = vec![];
         let Slice(xs, range) = source;
         let i = range.0;
-        let j = range.1;
         let ty = typing::array(&ast);
-        code.push(format!("val {bind} = {ty}();"));
+        if let Some((name, n)) = typing::pair_or_triple(ast) {
+            let mut inner = vec![];
+            for k in 0..n {
+                let v = format!("{xs}[{}]", i.clone() + Index(k.to_string()));
+                inner.push(unit_type_convert(&ast.0, &v));
+            }
+            let inner = inner.join(", ");
+            code.push(format!("val {bind}: {ty} = {name}({inner});"));
+            return code;
+        }
+        let n = Index(ast.1.expr.clone());
         let k = new_var();
-        code.push(format!("for ({k} in {i} until {j}) {{"));
-        let x = new_var();
-        let mut inner_code = bind_unit_type(x.clone(), &ast.0, &format!("{xs}[{k}]"));
-        inner_code.push(format!("{bind}.add({x})"));
-        append_code(&mut code, "\t", inner_code);
-        code.push(format!("}}"));
+        let v = unit_type_convert(&ast.0, &format!("{xs}[{i} + {k}]"));
+        code.push(format!("val {bind} = {ty}({n}) {{ {k} -> {v} }};"));
         code
     }
     fn matrix(bind: Bind, ast: &ast::Matrix) -> Result<Code, Error> {
         let mut code = vec![];
         let ty = format!("ArrayList<{}>", typing::tuple_like(&ast.0)?);
         let n = &ast.1;
-        let n = Index(n.0.clone());
+        let n = Index(n.expr.clone());
         code.push(format!("val {bind} = {ty}();"));
         let k = new_var();
         code.push(format!("for ({k} in 0 until {n}) {{"));
@@ -71,24 +76,25 @@ impl readline::Lang for Kotlin {
 fn bind_unit_type(bind: Bind, ast: &ast::UnitType, s: &str) -> Code {
     let mut code = vec![];
     let ty = typing::unit_type(&ast);
-    match ast {
+    code.push(format!("val {bind}: {ty} = {};", unit_type_convert(ast, s)));
+    code
+}
+fn unit_type_convert(ty: &ast::UnitType, s: &str) -> String {
+    match ty {
         ast::UnitType::Int => {
-            code.push(format!("val {bind}: {ty} = {s}.toInt();"));
+            format!("{s}.toInt()")
         }
         ast::UnitType::Int0 => {
-            code.push(format!("val {bind}: {ty} = ({s}.toInt() - 1);"));
+            format!("({s}.toInt() - 1)")
         }
         ast::UnitType::Float => {
-            code.push(format!("val {bind}: {ty} = {s}.toDouble();"));
-        }
-        ast::UnitType::Str => {
-            code.push(format!("val {bind}: {ty} = {s};"));
+            format!("{s}.toDouble()")
         }
+        ast::UnitType::Str => s.to_string(),
     }
-    code
 }
 type Type = String;
-mod typing {
+pub mod typing {
     use super::*;
     pub fn unit_type(ty: &ast::UnitType) -> Type {
         match ty {
@@ -98,13 +104,33 @@ mod typing {
             ast::UnitType::Str => "String".to_string(),
         }
     }
+    // Arrays are always allocated with the known length
+    // so we can use primitive arrays to avoid boxing.
+    fn primitive_array(ty: &ast::UnitType) -> Type {
+        match ty {
+            ast::UnitType::Int => "IntArray".to_string(),
+            ast::UnitType::Int0 => "IntArray".to_string(),
+            ast::UnitType::Float => "DoubleArray".to_string(),
+            ast::UnitType::Str => "Array<String>".to_string(),
+        }
+    }
+    // Arrays of length 2 or 3 become Pair and Triple to be destructured in loops.
+    pub fn pair_or_triple(ty: &ast::Array) -> Option<(&'static str, usize)> {
+        match ty.1.const_num {
+            Some(ast::ConstNum(2)) => Some(("Pair", 2)),
+            Some(ast::ConstNum(3)) => Some(("Triple", 3)),
+            _ => None,
+        }
+    }
     pub fn array(ty: &ast::Array) -> Type {
-        let inner = unit_type(&ty.0);
-        format!("ArrayList<{inner}>")
+        if let Some((name, n)) = pair_or_triple(ty) {
+            let inner = unit_type(&ty.0);
+            return format!("{name}<{}>", vec![inner; n].join(", "));
+        }
+        primitive_array(&ty.0)
     }
     pub fn list(ty: &ast::List) -> Type {
-        let inner = unit_type(&ty.0);
-        format!("ArrayList<{inner}>")
+        primitive_array(&ty.0)
     }
     pub fn tuple_like(ty: &ast::TupleLike) -> Result<Type, Error> {
         match ty {
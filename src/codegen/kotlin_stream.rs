@@ -0,0 +1,106 @@
+use super::kotlin::typing;
+use super::*;
+
+pub struct KotlinStream;
+impl stream::Lang for KotlinStream {
+    // readLine() and split() dominate the runtime in Kotlin
+    // so we scan the raw bytes from a buffered DataInputStream.
+    fn prelude() -> Code {
+        let read_token = shared_var("read_token");
+        let read_int = shared_var("read_int");
+        let mut code = vec![];
+        let input = new_var();
+        code.push(format!(
+            "val {input} = java.io.DataInputStream(java.io.BufferedInputStream(System.`in`, 1 shl 16))"
+        ));
+
+        let c = new_var();
+        let sb = new_var();
+        code.push(format!("fun {read_token}(): String {{"));
+        code.push(format!("\tvar {c} = {input}.read()"));
+        code.push(format!(
+            "\twhile ({c} != -1 && {c} <= ' '.code) {c} = {input}.read()"
+        ));
+        code.push(format!("\tval {sb} = StringBuilder()"));
+        code.push(format!(
+            "\twhile ({c} > ' '.code) {{ {sb}.append({c}.toChar()); {c} = {input}.read() }}"
+        ));
+        code.push(format!("\treturn {sb}.toString()"));
+        code.push("}".to_string());
+
+        let c = new_var();
+        let neg = new_var();
+        let x = new_var();
+        code.push(format!("fun {read_int}(): Int {{"));
+        code.push(format!("\tvar {c} = {input}.read()"));
+        code.push(format!(
+            "\twhile ({c} != -1 && {c} <= ' '.code) {c} = {input}.read()"
+        ));
+        code.push(format!("\tval {neg} = {c} == '-'.code"));
+        code.push(format!("\tif ({neg}) {c} = {input}.read()"));
+        code.push(format!("\tvar {x} = 0"));
+        code.push(format!(
+            "\twhile ('0'.code <= {c} && {c} <= '9'.code) {{ {x} = {x} * 10 + ({c} - '0'.code); {c} = {input}.read() }}"
+        ));
+        code.push(format!("\treturn if ({neg}) -{x} else {x}"));
+        code.push("}".to_string());
+        code
+    }
+    fn unit_type(bind: Bind, ast: &ast::UnitType) -> Code {
+        let ty = typing::unit_type(ast);
+        let code = format!("val {bind}: {ty} = {};", scan_unit_type(ast));
+        vec![code]
+    }
+    fn array(bind: Bind, ast: &ast::Array) -> Code {
+        let mut code = vec![];
+        let ty = typing::array(ast);
+        if let Some((name, n)) = typing::pair_or_triple(ast) {
+            let inner = vec![scan_unit_type(&ast.0); n].join(", ");
+            code.push(format!("val {bind}: {ty} = {name}({inner});"));
+            return code;
+        }
+        let n = Index(ast.1.expr.clone());
+        code.push(format!(
+            "val {bind} = {ty}({n}) {{ {} }};",
+            scan_unit_type(&ast.0)
+        ));
+        code
+    }
+    fn matrix(bind: Bind, ast: &ast::Matrix) -> Result<Code, Error> {
+        let mut code = vec![];
+        let ty = format!("ArrayList<{}>", typing::tuple_like(&ast.0)?);
+        let n = Index(ast.1.expr.clone());
+        code.push(format!("val {bind} = {ty}({n});"));
+        let k = new_var();
+        code.push(format!("for ({k} in 0 until {n}) {{"));
+
+        let mut inner_code = vec![];
+        let tuple = new_var();
+        inner_code.append(&mut Self::tuple_like(tuple.clone(), &ast.0)?);
+        inner_code.push(format!("{bind}.add({tuple});"));
+
+        append_code(&mut code, "\t", inner_code);
+        code.push("}".to_string());
+        Ok(code)
+    }
+    fn tuple(bind: Bind, mut elems: Vec<(&ast::TupleElem, Bind)>) -> Result<Code, Error> {
+        let n = elems.len();
+        if n > 1 {
+            return Err(Error::TupleNotSupported);
+        }
+        let e = elems.pop().unwrap();
+        let mut code = vec![];
+        code.push(format!("val {bind} = {};", e.1));
+        Ok(code)
+    }
+}
+fn scan_unit_type(ty: &ast::UnitType) -> String {
+    let read_token = shared_var("read_token");
+    let read_int = shared_var("read_int");
+    match ty {
+        ast::UnitType::Int => format!("{read_int}()"),
+        ast::UnitType::Int0 => format!("({read_int}() - 1)"),
+        ast::UnitType::Float => format!("{read_token}().toDouble()"),
+        ast::UnitType::Str => format!("{read_token}()"),
+    }
+}
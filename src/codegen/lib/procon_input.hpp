@@ -0,0 +1,8 @@
+// Defined at file scope so the readers can be member templates.
+struct ProconInput {
+	template <class T> T read() { T x; std::cin >> x; return x; }
+	template <class T> std::vector<T> vec(int n) { std::vector<T> v(n); for (auto& x : v) x = read<T>(); return v; }
+	// int0 is read as int and shifted.
+	int read0() { return read<int>() - 1; }
+	std::vector<int> vec0(int n) { std::vector<int> v(n); for (auto& x : v) x = read0(); return v; }
+};
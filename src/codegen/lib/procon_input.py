@@ -0,0 +1,24 @@
+import sys
+class ProconInput:
+	def __init__(self):
+		self.tokens = sys.stdin.buffer.read().split()
+		self.pos = 0
+	def next(self):
+		self.pos += 1
+		return self.tokens[self.pos - 1]
+	def read_int(self):
+		return int(self.next())
+	def read_int0(self):
+		return int(self.next()) - 1
+	def read_float(self):
+		return float(self.next())
+	def read_str(self):
+		return self.next().decode()
+	def vec_int(self, n):
+		return [self.read_int() for _ in range(n)]
+	def vec_int0(self, n):
+		return [self.read_int0() for _ in range(n)]
+	def vec_float(self, n):
+		return [self.read_float() for _ in range(n)]
+	def vec_str(self, n):
+		return [self.read_str() for _ in range(n)]
@@ -0,0 +1,46 @@
+#[allow(dead_code)]
+mod procon_input {
+    pub struct ProconInput {
+        tokens: std::str::SplitAsciiWhitespace<'static>,
+    }
+    impl ProconInput {
+        pub fn new() -> Self {
+            let mut s = String::new();
+            std::io::Read::read_to_string(&mut std::io::stdin(), &mut s).unwrap();
+            let s: &'static str = Box::leak(s.into_boxed_str());
+            ProconInput {
+                tokens: s.split_ascii_whitespace(),
+            }
+        }
+        fn next<T: std::str::FromStr>(&mut self) -> T
+        where
+            T::Err: std::fmt::Debug,
+        {
+            self.tokens.next().unwrap().parse().unwrap()
+        }
+        pub fn read_int(&mut self) -> i32 {
+            self.next()
+        }
+        pub fn read_int0(&mut self) -> i32 {
+            self.read_int() - 1
+        }
+        pub fn read_float(&mut self) -> f64 {
+            self.next()
+        }
+        pub fn read_str(&mut self) -> String {
+            self.next()
+        }
+        pub fn vec_int(&mut self, n: usize) -> Vec<i32> {
+            (0..n).map(|_| self.read_int()).collect()
+        }
+        pub fn vec_int0(&mut self, n: usize) -> Vec<i32> {
+            (0..n).map(|_| self.read_int0()).collect()
+        }
+        pub fn vec_float(&mut self, n: usize) -> Vec<f64> {
+            (0..n).map(|_| self.read_float()).collect()
+        }
+        pub fn vec_str(&mut self, n: usize) -> Vec<String> {
+            (0..n).map(|_| self.read_str()).collect()
+        }
+    }
+}
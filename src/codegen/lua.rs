@@ -0,0 +1,159 @@
+use super::*;
+
+// Arrays are 1-based tables.
+// If ZERO_BASED is false, int0 isn't decremented so the values
+// can be used as the indices of the tables as they are.
+pub struct Lua<const ZERO_BASED: bool>;
+impl<const ZERO_BASED: bool> readline::Lang for Lua<ZERO_BASED> {
+    fn read_line(bind: Bind) -> (Code, Index) {
+        let mut code = vec![];
+        code.push(format!("local {bind} = {{}}"));
+        code.push(format!(
+            "for x in io.read(\"l\"):gmatch(\"%S+\") do {bind}[#{bind} + 1] = x end"
+        ));
+        let n = new_var();
+        code.push(format!("local {n} = #{bind}"));
+        (code, Index(n.0))
+    }
+    fn unit_type(bind: Bind, ast: &ast::UnitType, source: Slice) -> Code {
+        let Slice(xs, range) = source;
+        let i = range.0;
+        let v = format!("{xs}[{i} + 1]");
+        let code = format!(
+            "local {bind} = {}",
+            unit_type_convert::<ZERO_BASED>(ast, &v)
+        );
+        vec![code]
+    }
+    fn array(bind: Bind, ast: &ast::Array, source: Slice) -> Code {
+        let mut code = vec![];
+        let Slice(xs, range) = source;
+        let i = range.0;
+        let n = Index(ast.1.expr.clone());
+        let k = new_var();
+        let v = format!("{xs}[{i} + {k}]");
+        code.push(format!("local {bind} = {{}}"));
+        code.push(format!(
+            "for {k} = 1, {n} do {bind}[{k}] = {} end",
+            unit_type_convert::<ZERO_BASED>(&ast.0, &v)
+        ));
+        code
+    }
+    fn matrix(bind: Bind, ast: &ast::Matrix) -> Result<Code, Error> {
+        let mut code = vec![];
+        let n = Index(ast.1.expr.clone());
+        code.push(format!("local {bind} = {{}}"));
+        let k = new_var();
+        code.push(format!("for {k} = 1, {n} do"));
+
+        let mut inner_code = vec![];
+        let line = new_var();
+        let (mut read_line, m) = Self::read_line(line.clone());
+        inner_code.append(&mut read_line);
+
+        let t = new_var();
+        let slice = Slice(line, Range(Index::zero(), m));
+        inner_code.append(&mut Self::tuple_like(t.clone(), &ast.0, slice)?);
+        inner_code.push(format!("{bind}[{k}] = {t}"));
+
+        append_code(&mut code, "  ", inner_code);
+        code.push("end".to_string());
+        Ok(code)
+    }
+    fn tuple(bind: Bind, elems: Vec<(&ast::TupleElem, Bind)>) -> Result<Code, Error> {
+        tuple(bind, elems)
+    }
+}
+
+pub struct LuaStream<const ZERO_BASED: bool>;
+impl<const ZERO_BASED: bool> stream::Lang for LuaStream<ZERO_BASED> {
+    // io.read can't read a word so strings are read char by char.
+    fn prelude() -> Code {
+        vec![
+            "local function readToken()".to_string(),
+            "  local c = io.read(1)".to_string(),
+            "  while c and c:match(\"%s\") do c = io.read(1) end".to_string(),
+            // io.read returns nil at EOF.
+            "  if not c then return nil end".to_string(),
+            "  local t = {}".to_string(),
+            "  while c and not c:match(\"%s\") do t[#t + 1] = c; c = io.read(1) end".to_string(),
+            "  return table.concat(t)".to_string(),
+            "end".to_string(),
+        ]
+    }
+    fn unit_type(bind: Bind, ast: &ast::UnitType) -> Code {
+        let code = format!("local {bind} = {}", scan_unit_type::<ZERO_BASED>(ast));
+        vec![code]
+    }
+    fn array(bind: Bind, ast: &ast::Array) -> Code {
+        let mut code = vec![];
+        let n = Index(ast.1.expr.clone());
+        let k = new_var();
+        code.push(format!("local {bind} = {{}}"));
+        code.push(format!(
+            "for {k} = 1, {n} do {bind}[{k}] = {} end",
+            scan_unit_type::<ZERO_BASED>(&ast.0)
+        ));
+        code
+    }
+    fn matrix(bind: Bind, ast: &ast::Matrix) -> Result<Code, Error> {
+        let mut code = vec![];
+        let n = Index(ast.1.expr.clone());
+        code.push(format!("local {bind} = {{}}"));
+        let k = new_var();
+        code.push(format!("for {k} = 1, {n} do"));
+
+        let mut inner_code = vec![];
+        let t = new_var();
+        inner_code.append(&mut Self::tuple_like(t.clone(), &ast.0)?);
+        inner_code.push(format!("{bind}[{k}] = {t}"));
+
+        append_code(&mut code, "  ", inner_code);
+        code.push("end".to_string());
+        Ok(code)
+    }
+    fn tuple(bind: Bind, elems: Vec<(&ast::TupleElem, Bind)>) -> Result<Code, Error> {
+        tuple(bind, elems)
+    }
+}
+
+fn tuple(bind: Bind, elems: Vec<(&ast::TupleElem, Bind)>) -> Result<Code, Error> {
+    let mut inner = vec![];
+    let n = elems.len();
+    for (_, e) in elems {
+        inner.push(e.0);
+    }
+    let inner = inner.join(", ");
+    let code = if n == 1 {
+        format!("local {bind} = {inner}")
+    } else {
+        format!("local {bind} = {{{inner}}}")
+    };
+    Ok(vec![code])
+}
+fn unit_type_convert<const ZERO_BASED: bool>(ty: &ast::UnitType, v: &str) -> String {
+    match ty {
+        ast::UnitType::Int => {
+            format!("tonumber({v})")
+        }
+        ast::UnitType::Int0 if ZERO_BASED => {
+            format!("tonumber({v}) - 1")
+        }
+        ast::UnitType::Int0 => {
+            format!("tonumber({v})")
+        }
+        ast::UnitType::Float => {
+            format!("tonumber({v})")
+        }
+        ast::UnitType::Str => v.to_string(),
+    }
+}
+fn scan_unit_type<const ZERO_BASED: bool>(ty: &ast::UnitType) -> String {
+    match ty {
+        ast::UnitType::Int => "io.read(\"n\")".to_string(),
+        ast::UnitType::Int0 if ZERO_BASED => "io.read(\"n\") - 1".to_string(),
+        ast::UnitType::Int0 => "io.read(\"n\")".to_string(),
+        ast::UnitType::Float => "io.read(\"n\")".to_string(),
+        ast::UnitType::Str => "readToken()".to_string(),
+    }
+}
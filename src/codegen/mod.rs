@@ -1,6 +1,6 @@
 use crate::ast;
 use crate::ast::*;
-use crate::new_id;
+use crate::{new_id, push_global, shared_id};
 
 pub mod readline {
     use super::*;
@@ -17,13 +17,13 @@ pub mod readline {
     impl GetArity for Array {
         fn arity(&self) -> Index {
             let len = &self.1;
-            Index(len.0.to_owned())
+            Index(len.expr.to_owned())
         }
     }
     impl GetArity for List {
         fn arity(&self) -> Index {
             let n = &self.1;
-            Index::n(1) + Index(n.0.to_owned())
+            Index::n(1) + Index(n.expr.to_owned())
         }
     }
     impl GetArity for TupleElem {
@@ -64,6 +64,10 @@ pub mod readline {
     }
 
     pub trait Lang {
+        // Code emitted once before the first line is read.
+        fn prelude() -> Code {
+            vec![]
+        }
         fn read_line(bind: Bind) -> (Code, Index);
         fn unit_type(bind: Bind, ast: &ast::UnitType, source: Slice) -> Code;
         fn array(bind: Bind, ast: &ast::Array, source: Slice) -> Code;
@@ -77,7 +81,7 @@ pub mod readline {
             let mid = Index(format!("{l}+1"));
             let mut code = vec![];
             let len_source = Slice(xs.clone(), Range(l, mid.clone()));
-            let n = Bind(ast.1 .0.clone());
+            let n = Bind(ast.1.expr.clone());
             code.append(&mut Self::unit_type(
                 n.clone(),
                 &ast::UnitType::Int,
@@ -85,7 +89,7 @@ pub mod readline {
             ));
 
             let arr_source = Slice(xs, Range(mid, r));
-            let len = Len(n.0);
+            let len = Len::new(n.0);
             code.append(&mut Self::array(bind, &ast::Array(ast.0, len), arr_source));
 
             code
@@ -144,7 +148,7 @@ pub mod readline {
     }
 
     pub fn emit<L: Lang>(root: ast::Root) -> anyhow::Result<String> {
-        let mut out: Vec<String> = vec![];
+        let mut out: Vec<String> = L::prelude();
         for line in root.0 {
             let mut n = 0;
             for Definition(_, typ) in &line.0 {
@@ -203,13 +207,17 @@ pub mod readline {
 pub mod stream {
     use super::*;
     pub trait Lang {
+        // Code emitted once before the first definition, e.g. a token scanner.
+        fn prelude() -> Code {
+            vec![]
+        }
         fn unit_type(bind: Bind, ast: &ast::UnitType) -> Code;
         fn array(bind: Bind, ast: &ast::Array) -> Code;
         fn list(bind: Bind, ast: &ast::List) -> Code {
             let mut code = vec![];
-            let n = Bind(ast.1 .0.clone());
+            let n = Bind(ast.1.expr.clone());
             code.append(&mut Self::unit_type(n.clone(), &ast::UnitType::Int));
-            let len = ast::Len(n.0);
+            let len = ast::Len::new(n.0);
             code.append(&mut Self::array(bind, &ast::Array(ast.0, len)));
             code
         }
@@ -252,7 +260,7 @@ pub mod stream {
         }
     }
     pub fn emit<L: Lang>(root: ast::Root) -> anyhow::Result<String> {
-        let mut out: Code = vec![];
+        let mut out: Code = L::prelude();
         for line in root.0 {
             for Definition(var, typ) in line.0 {
                 let var = Bind(var.0);
@@ -277,18 +285,44 @@ pub mod stream {
     }
 }
 
+pub mod c;
 pub mod cpp;
+pub mod cpp_fast;
 pub mod cpp_stream;
+pub mod crystal;
 pub mod csharp;
+pub mod csharp_stream;
+pub mod d;
+pub mod dart;
+pub mod elixir;
+pub mod fsharp;
 pub mod go_stream;
+pub mod haskell;
 pub mod java;
 pub mod java_stream;
+pub mod julia;
 pub mod kotlin;
+pub mod kotlin_stream;
+pub mod lua;
 pub mod nim;
+pub mod nim_stream;
+pub mod ocaml;
+pub mod php;
+pub mod procon_input;
 pub mod python;
+pub mod python_numpy;
+pub mod racket;
 pub mod ruby;
+pub mod ruby_stream;
 pub mod rust;
+pub mod rust_proconio;
+pub mod rust_stream;
+pub mod scala;
 pub mod swift;
+pub mod swift_stream;
+pub mod typescript;
+pub mod typescript_stream;
+pub mod zig;
 
 #[derive(Clone)]
 pub struct Bind(pub String);
@@ -299,7 +333,7 @@ impl std::fmt::Display for Bind {
 }
 
 #[derive(Clone)]
-struct Index(String);
+pub struct Index(String);
 impl std::fmt::Display for Index {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.0)
@@ -330,12 +364,19 @@ impl std::ops::Sub for Index {
     }
 }
 
-struct Range(pub Index, pub Index);
-struct Slice(pub Bind, pub Range);
+pub struct Range(pub Index, pub Index);
+pub struct Slice(pub Bind, pub Range);
 
 pub fn new_var() -> Bind {
     Bind(new_id())
 }
+pub fn shared_var(key: &'static str) -> Bind {
+    Bind(shared_id(key))
+}
+// Emits the code at file scope. The template has to have a { global } hook.
+pub fn global_code(code: Code) {
+    push_global(code)
+}
 
 pub type Code = Vec<String>;
 fn append_code(dest: &mut Code, indent: &str, src: Code) {
@@ -349,4 +390,6 @@ use thiserror::Error;
 pub enum Error {
     #[error("Tuple isn't supported.")]
     TupleNotSupported,
+    #[error("{0} can't be expressed.")]
+    NotExpressible(String),
 }
@@ -35,13 +35,24 @@ impl readline::Lang for Nim {
         let j = range.1;
         let mut code = vec![];
         let mapper = unit_type_mapper(&ast.0);
+        if let Some(ast::ConstNum(n)) = ast.1.const_num {
+            let k = new_var();
+            code.push(format!("var {bind}: {}", typing::array(ast)));
+            code.push(format!("for {k} in 0..<{n}:"));
+            let v = format!("{xs}[{i} + {k}]");
+            code.push(format!(
+                "    {bind}[{k}] = {}",
+                unit_type_convert(&ast.0, &v)
+            ));
+            return code;
+        }
         code.push(format!("let {bind} = {xs}[{i}..<{j}].map({mapper})"));
         code
     }
     fn matrix(bind: Bind, ast: &ast::Matrix) -> Result<Code, super::Error> {
         let mut code = vec![];
         let inner_ty = typing::tuple_like(&ast.0);
-        let len = &ast.1 .0;
+        let len = &ast.1.expr;
         code.push(format!("var {bind}: seq[{inner_ty}];"));
         code.push(format!("for i in 0..<{len}:"));
 
@@ -79,9 +90,9 @@ impl readline::Lang for Nim {
     }
 }
 type Type = String;
-mod typing {
+pub mod typing {
     use super::*;
-    fn unit_type(ty: &ast::UnitType) -> Type {
+    pub fn unit_type(ty: &ast::UnitType) -> Type {
         match ty {
             ast::UnitType::Int => "int".to_string(),
             ast::UnitType::Int0 => "int".to_string(),
@@ -89,17 +100,20 @@ mod typing {
             ast::UnitType::Str => "string".to_string(),
         }
     }
-    fn array(ty: &ast::Array) -> Type {
+    pub fn array(ty: &ast::Array) -> Type {
         let inner = &ty.0;
         let inner = unit_type(inner);
-        format!("seq[{inner}]")
+        match ty.1.const_num {
+            Some(ast::ConstNum(n)) => format!("array[{n}, {inner}]"),
+            None => format!("seq[{inner}]"),
+        }
     }
-    fn list(ty: &ast::List) -> Type {
+    pub fn list(ty: &ast::List) -> Type {
         let inner = &ty.0;
         let inner = unit_type(inner);
         format!("seq[{inner}]")
     }
-    fn tuple(ty: &ast::Tuple) -> Type {
+    pub fn tuple(ty: &ast::Tuple) -> Type {
         let mut inner = vec![];
         let n = ty.0.len();
         for e in &ty.0 {
@@ -133,3 +147,11 @@ fn unit_type_mapper(ty: &ast::UnitType) -> &str {
         ast::UnitType::Str => "proc (x: string): string = x",
     }
 }
+fn unit_type_convert(ty: &ast::UnitType, v: &str) -> String {
+    match ty {
+        ast::UnitType::Int => format!("{v}.parseInt"),
+        ast::UnitType::Int0 => format!("({v}.parseInt - 1)"),
+        ast::UnitType::Float => format!("{v}.parseFloat"),
+        ast::UnitType::Str => v.to_string(),
+    }
+}
@@ -0,0 +1,77 @@
+use super::nim::typing;
+use super::*;
+
+pub struct NimStream;
+impl stream::Lang for NimStream {
+    fn prelude() -> Code {
+        let input = shared_var("input");
+        let cur = shared_var("cur");
+        let next_token = shared_var("next_token");
+        vec![
+            format!("let {input} = readAll(stdin).splitWhitespace()"),
+            format!("var {cur} = 0"),
+            format!("proc {next_token}(): string ="),
+            format!("    result = {input}[{cur}]"),
+            format!("    {cur} += 1"),
+        ]
+    }
+    fn unit_type(bind: Bind, ast: &ast::UnitType) -> Code {
+        let code = format!("let {bind} = {}", scan_unit_type(ast));
+        vec![code]
+    }
+    fn array(bind: Bind, ast: &ast::Array) -> Code {
+        let mut code = vec![];
+        let ty = typing::unit_type(&ast.0);
+        let n = Index(ast.1.expr.clone());
+        if ast.1.const_num.is_some() {
+            let k = new_var();
+            code.push(format!("var {bind}: {}", typing::array(ast)));
+            code.push(format!("for {k} in 0..<{n}:"));
+            code.push(format!("    {bind}[{k}] = {}", scan_unit_type(&ast.0)));
+            return code;
+        }
+        code.push(format!("var {bind} = newSeqOfCap[{ty}]({n})"));
+        code.push(format!("for _ in 0..<{n}:"));
+        code.push(format!("    {bind}.add({})", scan_unit_type(&ast.0)));
+        code
+    }
+    fn matrix(bind: Bind, ast: &ast::Matrix) -> Result<Code, Error> {
+        let mut code = vec![];
+        let inner_ty = typing::tuple_like(&ast.0);
+        let n = Index(ast.1.expr.clone());
+        code.push(format!("var {bind} = newSeqOfCap[{inner_ty}]({n})"));
+        code.push(format!("for _ in 0..<{n}:"));
+
+        let mut inner_code = vec![];
+        let t = new_var();
+        inner_code.append(&mut Self::tuple_like(t.clone(), &ast.0)?);
+        inner_code.push(format!("{bind}.add({t})"));
+
+        append_code(&mut code, "    ", inner_code);
+        Ok(code)
+    }
+    fn tuple(bind: Bind, elems: Vec<(&ast::TupleElem, Bind)>) -> Result<Code, Error> {
+        let mut code = vec![];
+        let mut inner = vec![];
+        let n = elems.len();
+        for (_, var) in elems {
+            inner.push(var.0);
+        }
+        let inner = inner.join(",");
+        if n == 1 {
+            code.push(format!("let {bind} = {inner}"));
+        } else {
+            code.push(format!("let {bind} = ({inner})"));
+        }
+        Ok(code)
+    }
+}
+fn scan_unit_type(ty: &ast::UnitType) -> String {
+    let next_token = shared_var("next_token");
+    match ty {
+        ast::UnitType::Int => format!("{next_token}().parseInt"),
+        ast::UnitType::Int0 => format!("({next_token}().parseInt - 1)"),
+        ast::UnitType::Float => format!("{next_token}().parseFloat"),
+        ast::UnitType::Str => format!("{next_token}()"),
+    }
+}
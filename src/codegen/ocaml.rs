@@ -0,0 +1,55 @@
+use super::*;
+
+// Every binding is emitted as `let .. in` so the whole parser
+// becomes a single expression that can be placed in a function body.
+pub struct OCaml;
+impl stream::Lang for OCaml {
+    fn unit_type(bind: Bind, ast: &ast::UnitType) -> Code {
+        let code = format!("let {bind} = {} in", scan_unit_type(ast));
+        vec![code]
+    }
+    fn array(bind: Bind, ast: &ast::Array) -> Code {
+        let n = Index(ast.1.expr.clone());
+        let code = format!(
+            "let {bind} = Array.init ({n}) (fun _ -> {}) in",
+            scan_unit_type(&ast.0)
+        );
+        vec![code]
+    }
+    fn matrix(bind: Bind, ast: &ast::Matrix) -> Result<Code, Error> {
+        let mut code = vec![];
+        let n = Index(ast.1.expr.clone());
+        code.push(format!("let {bind} = Array.init ({n}) (fun _ ->"));
+
+        let mut inner_code = vec![];
+        let t = new_var();
+        inner_code.append(&mut Self::tuple_like(t.clone(), &ast.0)?);
+        inner_code.push(format!("{t}"));
+        append_code(&mut code, "  ", inner_code);
+
+        code.push(") in".to_string());
+        Ok(code)
+    }
+    fn tuple(bind: Bind, elems: Vec<(&ast::TupleElem, Bind)>) -> Result<Code, Error> {
+        let mut inner = vec![];
+        let n = elems.len();
+        for (_, e) in elems {
+            inner.push(e.0);
+        }
+        let inner = inner.join(", ");
+        let code = if n == 1 {
+            format!("let {bind} = {inner} in")
+        } else {
+            format!("let {bind} = ({inner}) in")
+        };
+        Ok(vec![code])
+    }
+}
+fn scan_unit_type(ty: &ast::UnitType) -> String {
+    match ty {
+        ast::UnitType::Int => "Scanf.scanf \" %d\" (fun x -> x)".to_string(),
+        ast::UnitType::Int0 => "Scanf.scanf \" %d\" (fun x -> x - 1)".to_string(),
+        ast::UnitType::Float => "Scanf.scanf \" %f\" (fun x -> x)".to_string(),
+        ast::UnitType::Str => "Scanf.scanf \" %s\" (fun x -> x)".to_string(),
+    }
+}
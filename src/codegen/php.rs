@@ -0,0 +1,197 @@
+use super::*;
+
+pub struct Php;
+impl readline::Lang for Php {
+    fn read_line(bind: Bind) -> (Code, Index) {
+        let mut code = vec![];
+        code.push(format!("${bind} = explode(' ', trim(fgets(STDIN)));"));
+        let n = new_var();
+        code.push(format!("${n} = count(${bind});"));
+        (code, Index(n.0))
+    }
+    fn unit_type(bind: Bind, ast: &ast::UnitType, source: Slice) -> Code {
+        let mut code = vec![];
+        let Slice(xs, range) = source;
+        let i = sigil(&range.0 .0);
+        let ty = typing::unit_type(ast);
+        let v = format!("${xs}[{i}]");
+        code.push(format!("/** @var {ty} ${bind} */"));
+        code.push(format!("${bind} = {};", unit_type_convert(ast, &v)));
+        code
+    }
+    fn array(bind: Bind, ast: &ast::Array, source: Slice) -> Code {
+        let mut code = vec![];
+        let Slice(xs, range) = source;
+        let i = sigil(&range.0 .0);
+        let n = sigil(&ast.1.expr);
+        let ty = typing::array(ast);
+        let v = format!("array_slice(${xs}, {i}, {n})");
+        let rhs = match &ast.0 {
+            ast::UnitType::Str => v,
+            x => format!("array_map(fn($x) => {}, {v})", unit_type_convert(x, "$x")),
+        };
+        code.push(format!("/** @var {ty} ${bind} */"));
+        code.push(format!("${bind} = {rhs};"));
+        code
+    }
+    fn matrix(bind: Bind, ast: &ast::Matrix) -> Result<Code, Error> {
+        let mut code = vec![];
+        let ty = typing::tuple_like(&ast.0);
+        let n = sigil(&ast.1.expr);
+        code.push(format!("/** @var {ty}[] ${bind} */"));
+        code.push(format!("${bind} = [];"));
+        let k = new_var();
+        code.push(format!("for (${k} = 0; ${k} < {n}; ${k}++) {{"));
+
+        let mut inner_code = vec![];
+        let line = new_var();
+        let (mut read_line, m) = Self::read_line(line.clone());
+        inner_code.append(&mut read_line);
+
+        let t = new_var();
+        let slice = Slice(line, Range(Index::zero(), m));
+        inner_code.append(&mut Self::tuple_like(t.clone(), &ast.0, slice)?);
+        inner_code.push(format!("${bind}[] = ${t};"));
+
+        append_code(&mut code, "    ", inner_code);
+        code.push("}".to_string());
+        Ok(code)
+    }
+    fn tuple(bind: Bind, elems: Vec<(&ast::TupleElem, Bind)>) -> Result<Code, Error> {
+        let mut code = vec![];
+        let mut tys = vec![];
+        let mut inner = vec![];
+        let n = elems.len();
+        for (ty, e) in elems {
+            tys.push(typing::tuple_elem(ty));
+            inner.push(format!("${e}"));
+        }
+        let inner = inner.join(", ");
+        if n == 1 {
+            code.push(format!("/** @var {} ${bind} */", tys[0]));
+            code.push(format!("${bind} = {inner};"));
+        } else {
+            code.push(format!("/** @var array{{{}}} ${bind} */", tys.join(", ")));
+            code.push(format!("${bind} = [{inner}];"));
+        }
+        Ok(code)
+    }
+}
+
+// A line of only unit types is read at once by fscanf.
+fn scan_line(line: &ast::Line) -> Option<Code> {
+    let mut code = vec![];
+    let mut formats = vec![];
+    let mut binds = vec![];
+    let mut decrements = vec![];
+    for Definition(var, typ) in &line.0 {
+        let ty = match typ {
+            ast::Type::UnitType(x) => x,
+            _ => return None,
+        };
+        let format = match ty {
+            ast::UnitType::Int | ast::UnitType::Int0 => "%d",
+            ast::UnitType::Float => "%f",
+            ast::UnitType::Str => "%s",
+        };
+        formats.push(format);
+        let bind = &var.0;
+        code.push(format!("/** @var {} ${bind} */", typing::unit_type(ty)));
+        binds.push(format!("${bind}"));
+        if let ast::UnitType::Int0 = ty {
+            decrements.push(format!("${bind}--;"));
+        }
+    }
+    let formats = formats.join(" ");
+    let binds = binds.join(", ");
+    code.push(format!("fscanf(STDIN, \"{formats}\", {binds});"));
+    code.append(&mut decrements);
+    Some(code)
+}
+
+pub fn emit(root: ast::Root) -> anyhow::Result<String> {
+    let mut out = vec![];
+    for line in root.0 {
+        match scan_line(&line) {
+            Some(mut code) => out.append(&mut code),
+            None => out.push(readline::emit::<Php>(ast::Root(vec![line]))?),
+        }
+    }
+    Ok(out.join("\n"))
+}
+
+// Variables in the length expressions like "n+m" need the sigil.
+fn sigil(expr: &str) -> String {
+    let mut out = String::new();
+    let mut in_word = false;
+    for c in expr.chars() {
+        let is_word = c.is_ascii_alphanumeric() || c == '_';
+        if is_word && !in_word && !c.is_ascii_digit() {
+            out.push('$');
+        }
+        in_word = is_word;
+        out.push(c);
+    }
+    out
+}
+fn unit_type_convert(ty: &ast::UnitType, v: &str) -> String {
+    match ty {
+        ast::UnitType::Int => {
+            format!("(int){v}")
+        }
+        ast::UnitType::Int0 => {
+            format!("(int){v} - 1")
+        }
+        ast::UnitType::Float => {
+            format!("(float){v}")
+        }
+        ast::UnitType::Str => v.to_string(),
+    }
+}
+type Type = String;
+mod typing {
+    use super::*;
+    pub fn unit_type(ty: &ast::UnitType) -> Type {
+        match ty {
+            ast::UnitType::Int => "int".to_string(),
+            ast::UnitType::Int0 => "int".to_string(),
+            ast::UnitType::Float => "float".to_string(),
+            ast::UnitType::Str => "string".to_string(),
+        }
+    }
+    pub fn array(ty: &ast::Array) -> Type {
+        let inner = unit_type(&ty.0);
+        format!("{inner}[]")
+    }
+    pub fn list(ty: &ast::List) -> Type {
+        let inner = unit_type(&ty.0);
+        format!("{inner}[]")
+    }
+    pub fn tuple_elem(ty: &ast::TupleElem) -> Type {
+        match ty {
+            TupleElem::Array(x) => array(x),
+            TupleElem::List(x) => list(x),
+            TupleElem::UnitType(x) => unit_type(x),
+        }
+    }
+    pub fn tuple(ty: &ast::Tuple) -> Type {
+        let mut inner = vec![];
+        let n = ty.0.len();
+        for e in &ty.0 {
+            inner.push(tuple_elem(e));
+        }
+        let inner = inner.join(", ");
+        if n == 1 {
+            inner.to_string()
+        } else {
+            format!("array{{{inner}}}")
+        }
+    }
+    pub fn tuple_like(ty: &ast::TupleLike) -> Type {
+        match ty {
+            ast::TupleLike::Array(x) => array(x),
+            ast::TupleLike::List(x) => list(x),
+            ast::TupleLike::Tuple(x) => tuple(x),
+        }
+    }
+}
@@ -0,0 +1,222 @@
+use super::cpp::typing as cpp_typing;
+use super::rust::typing as rust_typing;
+use super::*;
+
+// Backends which emit a small helper library once and read the input
+// through it so the parsing code becomes a short call for each variable.
+// The Python and Rust libraries share the method names.
+// The C++ library has member templates instead, e.g. `in.vec<int>(n)`.
+
+fn lib_code(src: &str) -> Code {
+    src.lines().map(|x| x.to_string()).collect()
+}
+fn read_method(ty: &ast::UnitType) -> &'static str {
+    match ty {
+        ast::UnitType::Int => "read_int",
+        ast::UnitType::Int0 => "read_int0",
+        ast::UnitType::Float => "read_float",
+        ast::UnitType::Str => "read_str",
+    }
+}
+fn vec_method(ty: &ast::UnitType) -> &'static str {
+    match ty {
+        ast::UnitType::Int => "vec_int",
+        ast::UnitType::Int0 => "vec_int0",
+        ast::UnitType::Float => "vec_float",
+        ast::UnitType::Str => "vec_str",
+    }
+}
+
+pub struct CppLib;
+impl stream::Lang for CppLib {
+    fn prelude() -> Code {
+        global_code(lib_code(include_str!("lib/procon_input.hpp")));
+        let input = shared_var("input");
+        vec![format!("ProconInput {input};")]
+    }
+    fn unit_type(bind: Bind, ast: &ast::UnitType) -> Code {
+        let ty = cpp_typing::unit_type(ast);
+        let code = format!("{ty} {bind} = {};", cpp_read(ast));
+        vec![code]
+    }
+    fn array(bind: Bind, ast: &ast::Array) -> Code {
+        let ty = cpp_typing::array(ast);
+        let n = Index(ast.1.expr.clone());
+        let mut code = vec![];
+        if ast.1.const_num.is_some() {
+            let x = new_var();
+            code.push(format!("{ty} {bind};"));
+            code.push(format!(
+                "for (auto& {x} : {bind}) {x} = {};",
+                cpp_read(&ast.0)
+            ));
+        } else {
+            code.push(format!("{ty} {bind} = {};", cpp_vec(&ast.0, &n)));
+        }
+        code
+    }
+    fn matrix(bind: Bind, ast: &ast::Matrix) -> Result<Code, Error> {
+        let mut code = vec![];
+        let ty = format!("std::vector<{}>", cpp_typing::tuple_like(&ast.0));
+        let n = Index(ast.1.expr.clone());
+        code.push(format!("{ty} {bind};"));
+        code.push(format!("{bind}.reserve({n});"));
+        let k = new_var();
+        code.push(format!("for (int {k}=0; {k}<{n}; ++{k}) {{"));
+
+        let tuple = new_var();
+        let inner_code = Self::tuple_like(tuple.clone(), &ast.0)?;
+        append_code(&mut code, "\t", inner_code);
+        code.push(format!("\t{bind}.push_back({tuple});"));
+
+        code.push("}".to_string());
+        Ok(code)
+    }
+    fn tuple(bind: Bind, elems: Vec<(&ast::TupleElem, Bind)>) -> Result<Code, Error> {
+        let mut inner = vec![];
+        let n = elems.len();
+        for (_, e) in elems {
+            inner.push(e.0);
+        }
+        let inner = inner.join(", ");
+        let code = if n == 1 {
+            format!("auto {bind} = {inner};")
+        } else {
+            format!("auto {bind} = std::make_tuple({inner});")
+        };
+        Ok(vec![code])
+    }
+}
+
+fn cpp_read(ty: &ast::UnitType) -> String {
+    let input = shared_var("input");
+    match ty {
+        ast::UnitType::Int0 => format!("{input}.read0()"),
+        _ => format!("{input}.read<{}>()", cpp_typing::unit_type(ty)),
+    }
+}
+fn cpp_vec(ty: &ast::UnitType, n: &Index) -> String {
+    let input = shared_var("input");
+    match ty {
+        ast::UnitType::Int0 => format!("{input}.vec0({n})"),
+        _ => format!("{input}.vec<{}>({n})", cpp_typing::unit_type(ty)),
+    }
+}
+
+pub struct PythonLib;
+impl stream::Lang for PythonLib {
+    fn prelude() -> Code {
+        let mut code = lib_code(include_str!("lib/procon_input.py"));
+        code.push(format!("{} = ProconInput()", shared_var("input")));
+        code
+    }
+    fn unit_type(bind: Bind, ast: &ast::UnitType) -> Code {
+        let code = format!("{bind} = {}.{}()", shared_var("input"), read_method(ast));
+        vec![code]
+    }
+    fn array(bind: Bind, ast: &ast::Array) -> Code {
+        let n = Index(ast.1.expr.clone());
+        let code = format!(
+            "{bind} = {}.{}({n})",
+            shared_var("input"),
+            vec_method(&ast.0)
+        );
+        vec![code]
+    }
+    fn matrix(bind: Bind, ast: &ast::Matrix) -> Result<Code, Error> {
+        let mut code = vec![];
+        let n = Index(ast.1.expr.clone());
+        code.push(format!("{bind} = []"));
+        code.push(format!("for _ in range({n}):"));
+
+        let tuple = new_var();
+        let inner_code = Self::tuple_like(tuple.clone(), &ast.0)?;
+        append_code(&mut code, "\t", inner_code);
+        code.push(format!("\t{bind}.append({tuple})"));
+        Ok(code)
+    }
+    fn tuple(bind: Bind, elems: Vec<(&ast::TupleElem, Bind)>) -> Result<Code, Error> {
+        let mut inner = vec![];
+        let n = elems.len();
+        for (_, e) in elems {
+            inner.push(e.0);
+        }
+        let inner = inner.join(", ");
+        let code = if n == 1 {
+            format!("{bind} = {inner}")
+        } else {
+            format!("{bind} = ({inner})")
+        };
+        Ok(vec![code])
+    }
+}
+
+pub struct RustLib;
+impl stream::Lang for RustLib {
+    fn prelude() -> Code {
+        let mut code = lib_code(include_str!("lib/procon_input.rs"));
+        code.push(format!(
+            "let mut {} = procon_input::ProconInput::new();",
+            shared_var("input")
+        ));
+        code
+    }
+    fn unit_type(bind: Bind, ast: &ast::UnitType) -> Code {
+        let ty = rust_typing::unit_type(ast);
+        let code = format!(
+            "let {bind}: {ty} = {}.{}();",
+            shared_var("input"),
+            read_method(ast)
+        );
+        vec![code]
+    }
+    fn array(bind: Bind, ast: &ast::Array) -> Code {
+        let ty = rust_typing::array(ast);
+        let n = Index(ast.1.expr.clone());
+        let code = if ast.1.const_num.is_some() {
+            format!(
+                "let {bind}: {ty} = std::array::from_fn(|_| {}.{}());",
+                shared_var("input"),
+                read_method(&ast.0)
+            )
+        } else {
+            format!(
+                "let {bind}: {ty} = {}.{}(({n}) as usize);",
+                shared_var("input"),
+                vec_method(&ast.0)
+            )
+        };
+        vec![code]
+    }
+    fn matrix(bind: Bind, ast: &ast::Matrix) -> Result<Code, Error> {
+        let mut code = vec![];
+        let ty = format!("Vec<{}>", rust_typing::tuple_like(&ast.0));
+        let n = Index(ast.1.expr.clone());
+        code.push(format!(
+            "let mut {bind}: {ty} = Vec::with_capacity(({n}) as usize);"
+        ));
+        code.push(format!("for _ in 0..({n}) as usize {{"));
+
+        let tuple = new_var();
+        let inner_code = Self::tuple_like(tuple.clone(), &ast.0)?;
+        append_code(&mut code, "\t", inner_code);
+        code.push(format!("\t{bind}.push({tuple});"));
+
+        code.push("}".to_string());
+        Ok(code)
+    }
+    fn tuple(bind: Bind, elems: Vec<(&ast::TupleElem, Bind)>) -> Result<Code, Error> {
+        let mut inner = vec![];
+        let n = elems.len();
+        for (_, e) in elems {
+            inner.push(e.0);
+        }
+        let inner = inner.join(", ");
+        let code = if n == 1 {
+            format!("let {bind} = {inner};")
+        } else {
+            format!("let {bind} = ({inner});")
+        };
+        Ok(vec![code])
+    }
+}
@@ -28,7 +28,7 @@ impl readline::Lang for Python {
     fn matrix(bind: Bind, ast: &ast::Matrix) -> Result<Code, super::Error> {
         let ty = &ast.0;
         let len = &ast.1;
-        let rep = &len.0;
+        let rep = &len.expr;
         let mut out = vec![];
         out.push(format!("{bind} = []"));
         out.push(format!("for _ in range({rep}):"));
@@ -0,0 +1,87 @@
+use super::python::Python;
+use super::readline::GetArity;
+use super::*;
+
+// The whole input is split into tokens once in the prelude
+// and the values are sliced from them with a cursor.
+// Numeric arrays become np.ndarray.
+// Rectangular numeric matrices are sliced at once and reshaped.
+// The other shapes are converted by Python from a slice of the tokens.
+pub struct PythonNumpy;
+impl stream::Lang for PythonNumpy {
+    fn prelude() -> Code {
+        let tokens = shared_var("tokens");
+        let pos = shared_var("pos");
+        let mut code = vec![];
+        code.push("import sys".to_string());
+        code.push("import numpy as np".to_string());
+        code.push(format!("{tokens} = sys.stdin.read().split()"));
+        code.push(format!("{pos} = 0"));
+        code
+    }
+    fn unit_type(bind: Bind, ast: &ast::UnitType) -> Code {
+        let mut code = <Python as readline::Lang>::unit_type(bind, ast, take(ast.arity()));
+        code.push(advance(ast.arity()));
+        code
+    }
+    fn array(bind: Bind, ast: &ast::Array) -> Code {
+        let Some(dtype) = dtype(&ast.0) else {
+            let mut code = <Python as readline::Lang>::array(bind, ast, take(ast.arity()));
+            code.push(advance(ast.arity()));
+            return code;
+        };
+        let mut code = vec![];
+        let rhs = ndarray(&ast.0, &take(ast.arity()), dtype);
+        code.push(format!("{bind} = {rhs}"));
+        code.push(advance(ast.arity()));
+        code
+    }
+    fn matrix(bind: Bind, ast: &ast::Matrix) -> Result<Code, Error> {
+        let n = Index(ast.1.expr.clone());
+        let mut code = vec![];
+        if let ast::TupleLike::Array(row) = &ast.0 {
+            if let Some(dtype) = dtype(&row.0) {
+                let m = Index(row.1.expr.clone());
+                let size = Index(format!("({n}) * ({m})"));
+                let rhs = ndarray(&row.0, &take(size.clone()), dtype);
+                code.push(format!("{bind} = {rhs}.reshape({n}, {m})"));
+                code.push(advance(size));
+                return Ok(code);
+            }
+        }
+        code.push(format!("{bind} = []"));
+        code.push(format!("for _ in range({n}):"));
+        let t = new_var();
+        append_code(&mut code, "\t", Self::tuple_like(t.clone(), &ast.0)?);
+        code.push(format!("\t{bind}.append({t})"));
+        Ok(code)
+    }
+    fn tuple(bind: Bind, elems: Vec<(&ast::TupleElem, Bind)>) -> Result<Code, Error> {
+        <Python as readline::Lang>::tuple(bind, elems)
+    }
+}
+// The next n tokens.
+fn take(n: Index) -> Slice {
+    let pos = shared_var("pos");
+    let range = Range(Index(pos.0.clone()), Index(pos.0) + n);
+    Slice(shared_var("tokens"), range)
+}
+fn advance(n: Index) -> String {
+    format!("{} += {n}", shared_var("pos"))
+}
+fn dtype(ty: &ast::UnitType) -> Option<&'static str> {
+    match ty {
+        ast::UnitType::Int | ast::UnitType::Int0 => Some("np.int64"),
+        ast::UnitType::Float => Some("np.float64"),
+        ast::UnitType::Str => None,
+    }
+}
+// int0 is shifted in bulk.
+fn ndarray(ty: &ast::UnitType, tokens: &Slice, dtype: &str) -> String {
+    let Slice(xs, Range(l, r)) = tokens;
+    let tokens = format!("{xs}[{l}:{r}]");
+    match ty {
+        ast::UnitType::Int0 => format!("(np.array({tokens}, dtype={dtype}) - 1)"),
+        _ => format!("np.array({tokens}, dtype={dtype})"),
+    }
+}
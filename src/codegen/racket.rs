@@ -0,0 +1,184 @@
+use super::*;
+
+// Every binding is a clause of a single let* form.
+// The caller writes the body and closes the form.
+pub struct Racket;
+impl stream::Lang for Racket {
+    // (read) would intern a string token as a symbol so
+    // the token is matched as bytes instead.
+    fn prelude() -> Code {
+        vec![
+            "[read-token (lambda ()".to_string(),
+            "  (bytes->string/utf-8 (car (regexp-match #px\"\\\\S+\" (current-input-port)))))]"
+                .to_string(),
+        ]
+    }
+    fn unit_type(bind: Bind, ast: &ast::UnitType) -> Code {
+        let code = format!("[{bind} {}]", scan_unit_type(ast));
+        vec![code]
+    }
+    fn array(bind: Bind, ast: &ast::Array) -> Code {
+        let n = prefix(&ast.1.expr).expect("checked in emit");
+        let code = format!(
+            "[{bind} (for/vector #:length {n} ([_ (in-range {n})]) {})]",
+            scan_unit_type(&ast.0)
+        );
+        vec![code]
+    }
+    fn matrix(bind: Bind, ast: &ast::Matrix) -> Result<Code, Error> {
+        let mut code = vec![];
+        let n = prefix(&ast.1.expr)?;
+        code.push(format!(
+            "[{bind} (for/vector #:length {n} ([_ (in-range {n})])"
+        ));
+        code.push("  (let* (".to_string());
+
+        let t = new_var();
+        let inner_code = Self::tuple_like(t.clone(), &ast.0)?;
+        append_code(&mut code, "    ", inner_code);
+
+        code.push("    )".to_string());
+        code.push(format!("    {t}))]"));
+        Ok(code)
+    }
+    fn tuple(bind: Bind, elems: Vec<(&ast::TupleElem, Bind)>) -> Result<Code, Error> {
+        let mut inner = vec![];
+        let n = elems.len();
+        for (_, e) in elems {
+            inner.push(e.0);
+        }
+        let inner = inner.join(" ");
+        let code = if n == 1 {
+            format!("[{bind} {inner}]")
+        } else {
+            format!("[{bind} (list {inner})]")
+        };
+        Ok(vec![code])
+    }
+}
+fn scan_unit_type(ty: &ast::UnitType) -> String {
+    match ty {
+        ast::UnitType::Int => "(read)".to_string(),
+        ast::UnitType::Int0 => "(sub1 (read))".to_string(),
+        ast::UnitType::Float => "(exact->inexact (read))".to_string(),
+        ast::UnitType::Str => "(read-token)".to_string(),
+    }
+}
+
+// Converts an infix length expression like "n+m" to "(+ n m)".
+fn prefix(expr: &str) -> Result<String, Error> {
+    let tokens = tokenize(expr);
+    let mut pos = 0;
+    match prefix_expr(&tokens, &mut pos) {
+        Some(e) if pos == tokens.len() => Ok(e),
+        _ => Err(Error::NotExpressible(format!("Length `{expr}`"))),
+    }
+}
+fn tokenize(expr: &str) -> Vec<String> {
+    let mut out = vec![];
+    let mut cur = String::new();
+    for c in expr.chars() {
+        if c.is_ascii_alphanumeric() || c == '_' {
+            cur.push(c);
+            continue;
+        }
+        if !cur.is_empty() {
+            out.push(cur.clone());
+            cur.clear();
+        }
+        if !c.is_whitespace() {
+            out.push(c.to_string());
+        }
+    }
+    if !cur.is_empty() {
+        out.push(cur);
+    }
+    out
+}
+fn prefix_expr(tokens: &[String], pos: &mut usize) -> Option<String> {
+    let mut lhs = prefix_term(tokens, pos)?;
+    while *pos < tokens.len() && (tokens[*pos] == "+" || tokens[*pos] == "-") {
+        let op = tokens[*pos].clone();
+        *pos += 1;
+        let rhs = prefix_term(tokens, pos)?;
+        lhs = format!("({op} {lhs} {rhs})");
+    }
+    Some(lhs)
+}
+fn prefix_term(tokens: &[String], pos: &mut usize) -> Option<String> {
+    let mut lhs = prefix_factor(tokens, pos)?;
+    while *pos < tokens.len() && (tokens[*pos] == "*" || tokens[*pos] == "/") {
+        let op = match tokens[*pos].as_str() {
+            "*" => "*",
+            _ => "quotient",
+        };
+        *pos += 1;
+        let rhs = prefix_factor(tokens, pos)?;
+        lhs = format!("({op} {lhs} {rhs})");
+    }
+    Some(lhs)
+}
+// None if the tokens end early or a parenthesis isn't closed.
+fn prefix_factor(tokens: &[String], pos: &mut usize) -> Option<String> {
+    let token = tokens.get(*pos)?.clone();
+    *pos += 1;
+    if token == "(" {
+        let e = prefix_expr(tokens, pos)?;
+        if tokens.get(*pos)? != ")" {
+            return None;
+        }
+        *pos += 1;
+        Some(e)
+    } else if token.starts_with(|c: char| c.is_ascii_alphanumeric() || c == '_') {
+        Some(token)
+    } else {
+        None
+    }
+}
+// Every length in the input, checked by emit before the translation.
+fn lens(root: &ast::Root) -> Vec<&ast::Len> {
+    fn tuple_like(x: &ast::TupleLike) -> Vec<&ast::Len> {
+        match x {
+            ast::TupleLike::Array(x) => vec![&x.1],
+            ast::TupleLike::List(_) => vec![],
+            ast::TupleLike::Tuple(ast::Tuple(elems)) => elems
+                .iter()
+                .filter_map(|e| match e {
+                    TupleElem::Array(x) => Some(&x.1),
+                    _ => None,
+                })
+                .collect(),
+        }
+    }
+    let mut out = vec![];
+    for line in &root.0 {
+        for Definition(_, typ) in &line.0 {
+            match typ {
+                Type::TupleLike(x) => out.append(&mut tuple_like(x)),
+                Type::Matrix(x) => {
+                    out.push(&x.1);
+                    out.append(&mut tuple_like(&x.0));
+                }
+                _ => {}
+            }
+        }
+    }
+    out
+}
+
+pub fn emit(root: ast::Root) -> anyhow::Result<String> {
+    // Lang::array can't fail so a malformed length is reported here.
+    for len in lens(&root) {
+        prefix(&len.expr)?;
+    }
+    let code = stream::emit::<Racket>(root)?;
+    let mut out = vec![];
+    out.push("(let* (".to_string());
+    append_code(
+        &mut out,
+        "  ",
+        code.lines().map(|x| x.to_string()).collect(),
+    );
+    out.push("  )".to_string());
+    Ok(out.join("\n"))
+}
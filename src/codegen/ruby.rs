@@ -29,7 +29,7 @@ impl readline::Lang for Ruby {
     fn matrix(bind: Bind, ast: &ast::Matrix) -> Result<Code, super::Error> {
         let ty = &ast.0;
         let len = &ast.1;
-        let rep = &len.0;
+        let rep = &len.expr;
         let mut code = vec![];
         code.push(format!("{bind} = []"));
         code.push(format!("{rep}.times do"));
@@ -64,7 +64,7 @@ impl readline::Lang for Ruby {
         Ok(vec![code])
     }
 }
-fn unit_type_convert(ty: &ast::UnitType, v: &str) -> String {
+pub fn unit_type_convert(ty: &ast::UnitType, v: &str) -> String {
     match ty {
         ast::UnitType::Int => {
             format!("{v}.to_i")
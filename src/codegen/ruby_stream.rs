@@ -0,0 +1,61 @@
+use super::ruby::unit_type_convert;
+use super::*;
+
+pub struct RubyStream;
+impl stream::Lang for RubyStream {
+    fn prelude() -> Code {
+        let input = shared_var("input");
+        let cur = shared_var("cur");
+        vec![format!("{input} = STDIN.read.split"), format!("{cur} = 0")]
+    }
+    fn unit_type(bind: Bind, ast: &ast::UnitType) -> Code {
+        let input = shared_var("input");
+        let cur = shared_var("cur");
+        let mut code = vec![];
+        let v = format!("{input}[{cur}]");
+        code.push(format!("{bind} = {}", unit_type_convert(ast, &v)));
+        code.push(format!("{cur} += 1"));
+        code
+    }
+    fn array(bind: Bind, ast: &ast::Array) -> Code {
+        let input = shared_var("input");
+        let cur = shared_var("cur");
+        let mut code = vec![];
+        let n = Index(ast.1.expr.clone());
+        code.push(format!(
+            "{bind} = {input}[{cur}, {n}].map {{ |x| {} }}",
+            unit_type_convert(&ast.0, "x")
+        ));
+        code.push(format!("{cur} += {n}"));
+        code
+    }
+    fn matrix(bind: Bind, ast: &ast::Matrix) -> Result<Code, Error> {
+        let mut code = vec![];
+        let rep = &ast.1.expr;
+        code.push(format!("{bind} = []"));
+        code.push(format!("{rep}.times do"));
+
+        let mut inner_code = vec![];
+        let t = new_var();
+        inner_code.append(&mut Self::tuple_like(t.clone(), &ast.0)?);
+        inner_code.push(format!("{bind} << {t}"));
+
+        append_code(&mut code, "  ", inner_code);
+        code.push("end".to_string());
+        Ok(code)
+    }
+    fn tuple(bind: Bind, elems: Vec<(&ast::TupleElem, Bind)>) -> Result<Code, Error> {
+        let mut inner = vec![];
+        let n = elems.len();
+        for (_, e) in elems {
+            inner.push(e.0);
+        }
+        let inner = inner.join(", ");
+        let code = if n == 1 {
+            format!("{bind} = {inner}")
+        } else {
+            format!("{bind} = [{inner}]")
+        };
+        Ok(vec![code])
+    }
+}
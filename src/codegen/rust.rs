@@ -26,6 +26,15 @@ impl readline::Lang for Rust {
         let Slice(xs, range) = source;
         let i = range.0;
         let j = range.1;
+        if ast.1.const_num.is_some() {
+            let ty = typing::array(ast);
+            let v = format!("{xs}[({i}) as usize + k]");
+            code.push(format!(
+                "let {bind}: {ty} = std::array::from_fn(|k| {});",
+                unit_type_convert(&ast.0, &v)
+            ));
+            return code;
+        }
         code.push(format!("let mut {bind} = vec![];"));
         code.push(format!("for i in ({i}) as usize..({j}) as usize {{"));
         let v = format!("{xs}[i]");
@@ -37,7 +46,7 @@ impl readline::Lang for Rust {
         let mut code = vec![];
         code.push(format!("let mut {bind} = vec![];"));
         let n = &ast.1;
-        let n = Index(n.0.clone());
+        let n = Index(n.expr.clone());
         code.push(format!("for i in 0..({n}) as usize {{"));
 
         let line = new_var();
@@ -66,7 +75,7 @@ impl readline::Lang for Rust {
 }
 // In Rust, the default types for integer number is i32 and floating number is f64.
 // https://github.com/rust-lang/rfcs/blob/master/text/0212-restore-int-fallback.md
-fn unit_type_convert(ty: &ast::UnitType, v: &str) -> String {
+pub fn unit_type_convert(ty: &ast::UnitType, v: &str) -> String {
     match ty {
         ast::UnitType::Int => {
             format!("{v}.parse::<i32>().unwrap()")
@@ -82,3 +91,51 @@ fn unit_type_convert(ty: &ast::UnitType, v: &str) -> String {
         }
     }
 }
+type Type = String;
+pub mod typing {
+    use super::*;
+    pub fn unit_type(ty: &ast::UnitType) -> Type {
+        match ty {
+            ast::UnitType::Int => "i32".to_string(),
+            ast::UnitType::Int0 => "i32".to_string(),
+            ast::UnitType::Float => "f64".to_string(),
+            ast::UnitType::Str => "String".to_string(),
+        }
+    }
+    pub fn array(ty: &ast::Array) -> Type {
+        let inner = unit_type(&ty.0);
+        match ty.1.const_num {
+            Some(ast::ConstNum(n)) => format!("[{inner}; {n}]"),
+            None => format!("Vec<{inner}>"),
+        }
+    }
+    pub fn list(ty: &ast::List) -> Type {
+        let inner = unit_type(&ty.0);
+        format!("Vec<{inner}>")
+    }
+    pub fn tuple_like(ty: &ast::TupleLike) -> Type {
+        match ty {
+            ast::TupleLike::Array(x) => array(x),
+            ast::TupleLike::List(x) => list(x),
+            ast::TupleLike::Tuple(x) => tuple(x),
+        }
+    }
+    pub fn tuple(ty: &ast::Tuple) -> Type {
+        let mut inner = vec![];
+        let n = ty.0.len();
+        for e in &ty.0 {
+            let ty = match e {
+                TupleElem::Array(x) => array(x),
+                TupleElem::List(x) => list(x),
+                TupleElem::UnitType(x) => unit_type(x),
+            };
+            inner.push(ty);
+        }
+        let inner = inner.join(", ");
+        if n == 1 {
+            inner.to_string()
+        } else {
+            format!("({inner})")
+        }
+    }
+}
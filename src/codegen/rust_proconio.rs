@@ -0,0 +1,117 @@
+use super::*;
+use std::collections::HashMap;
+
+// Translates the whole input into a single proconio's input! invocation.
+// The lines are ignored because proconio reads the input as a stream.
+// The integers used in the lengths are read as usize.
+pub fn emit(root: ast::Root) -> anyhow::Result<String> {
+    let mut vars = HashMap::new();
+    let mut lens = vec![];
+    for Line(defs) in &root.0 {
+        for Definition(var, typ) in defs {
+            match typ {
+                ast::Type::UnitType(x) => {
+                    vars.insert(var.0.as_str(), *x);
+                }
+                ast::Type::TupleLike(x) => {
+                    lens.append(&mut tuple_like_lens(x));
+                }
+                ast::Type::Matrix(x) => {
+                    lens.push(x.1.expr.as_str());
+                    lens.append(&mut tuple_like_lens(&x.0));
+                }
+            }
+        }
+    }
+
+    let mut usize_vars = vec![];
+    for len in lens {
+        for v in idents(len) {
+            match vars.get(v.as_str()) {
+                Some(ast::UnitType::Int) | Some(ast::UnitType::Int0) => {
+                    usize_vars.push(v);
+                }
+                _ => {
+                    let e = Error::NotExpressible(format!("Length `{len}` in input!"));
+                    return Err(e.into());
+                }
+            }
+        }
+    }
+
+    let mut code = vec![];
+    code.push("input! {".to_string());
+    for Line(defs) in &root.0 {
+        for Definition(var, typ) in defs {
+            let ty = match typ {
+                ast::Type::UnitType(ast::UnitType::Int) if usize_vars.contains(&var.0) => {
+                    "usize".to_string()
+                }
+                ast::Type::UnitType(x) => unit_type(x),
+                ast::Type::TupleLike(x) => tuple_like(x),
+                ast::Type::Matrix(x) => format!("[{}; {}]", tuple_like(&x.0), x.1.expr),
+            };
+            code.push(format!("    {}: {ty},", var.0));
+        }
+    }
+    code.push("}".to_string());
+    Ok(code.join("\n"))
+}
+fn tuple_like_lens(ty: &ast::TupleLike) -> Vec<&str> {
+    match ty {
+        ast::TupleLike::Array(x) => vec![x.1.expr.as_str()],
+        ast::TupleLike::List(_) => vec![],
+        ast::TupleLike::Tuple(x) => {
+            let mut out = vec![];
+            for e in &x.0 {
+                if let TupleElem::Array(x) = e {
+                    out.push(x.1.expr.as_str());
+                }
+            }
+            out
+        }
+    }
+}
+// The variables in a length expression like "n+m".
+fn idents(expr: &str) -> Vec<String> {
+    let mut out = vec![];
+    let mut cur = String::new();
+    for c in expr.chars().chain(std::iter::once(' ')) {
+        if c.is_ascii_alphanumeric() || c == '_' {
+            cur.push(c);
+            continue;
+        }
+        if cur.starts_with(|c: char| !c.is_ascii_digit()) {
+            out.push(cur.clone());
+        }
+        cur.clear();
+    }
+    out
+}
+fn unit_type(ty: &ast::UnitType) -> String {
+    match ty {
+        ast::UnitType::Int => "i64".to_string(),
+        ast::UnitType::Int0 => "Usize1".to_string(),
+        ast::UnitType::Float => "f64".to_string(),
+        ast::UnitType::Str => "String".to_string(),
+    }
+}
+fn tuple_elem(ty: &ast::TupleElem) -> String {
+    match ty {
+        TupleElem::UnitType(x) => unit_type(x),
+        TupleElem::Array(x) => format!("[{}; {}]", unit_type(&x.0), x.1.expr),
+        TupleElem::List(x) => format!("[{}]", unit_type(&x.0)),
+    }
+}
+// A tuple of one element is the element itself.
+fn tuple_like(ty: &ast::TupleLike) -> String {
+    match ty {
+        ast::TupleLike::Array(x) => format!("[{}; {}]", unit_type(&x.0), x.1.expr),
+        ast::TupleLike::List(x) => format!("[{}]", unit_type(&x.0)),
+        ast::TupleLike::Tuple(x) if x.0.len() == 1 => tuple_elem(&x.0[0]),
+        ast::TupleLike::Tuple(x) => {
+            let inner: Vec<String> = x.0.iter().map(tuple_elem).collect();
+            format!("({})", inner.join(", "))
+        }
+    }
+}
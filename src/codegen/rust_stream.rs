@@ -0,0 +1,87 @@
+use super::rust::{typing, unit_type_convert};
+use super::*;
+
+pub struct RustStream;
+impl stream::Lang for RustStream {
+    fn prelude() -> Code {
+        let mut code = vec![];
+        let buf = new_var();
+        code.push(format!("let mut {buf} = String::new();"));
+        code.push(format!(
+            "std::io::Read::read_to_string(&mut std::io::stdin().lock(), &mut {buf}).unwrap();"
+        ));
+        let input = shared_var("input");
+        code.push(format!("let mut {input} = {buf}.split_ascii_whitespace();"));
+        code
+    }
+    fn unit_type(bind: Bind, ast: &ast::UnitType) -> Code {
+        let mut code = vec![];
+        code.append(&mut scan_unit_type(bind, ast));
+        code
+    }
+    fn array(bind: Bind, ast: &ast::Array) -> Code {
+        let mut code = vec![];
+        let ty = typing::array(ast);
+        let n = Index(ast.1.expr.clone());
+        if ast.1.const_num.is_some() {
+            let v = unit_type_convert(&ast.0, &next_token());
+            code.push(format!("let {bind}: {ty} = std::array::from_fn(|_| {v});"));
+            return code;
+        }
+        code.push(format!(
+            "let mut {bind}: {ty} = Vec::with_capacity(({n}) as usize);"
+        ));
+        code.push(format!("for _ in 0..({n}) as usize {{"));
+
+        let mut inner_code = vec![];
+        let x = new_var();
+        inner_code.append(&mut scan_unit_type(x.clone(), &ast.0));
+        inner_code.push(format!("{bind}.push({x});"));
+        append_code(&mut code, "\t", inner_code);
+
+        code.push("}".to_string());
+        code
+    }
+    fn matrix(bind: Bind, ast: &ast::Matrix) -> Result<Code, Error> {
+        let mut code = vec![];
+        let ty = format!("Vec<{}>", typing::tuple_like(&ast.0));
+        let n = Index(ast.1.expr.clone());
+        code.push(format!(
+            "let mut {bind}: {ty} = Vec::with_capacity(({n}) as usize);"
+        ));
+        code.push(format!("for _ in 0..({n}) as usize {{"));
+
+        let mut inner_code = vec![];
+        let tuple = new_var();
+        inner_code.append(&mut Self::tuple_like(tuple.clone(), &ast.0)?);
+        inner_code.push(format!("{bind}.push({tuple});"));
+        append_code(&mut code, "\t", inner_code);
+
+        code.push("}".to_string());
+        Ok(code)
+    }
+    fn tuple(bind: Bind, elems: Vec<(&ast::TupleElem, Bind)>) -> Result<Code, Error> {
+        let mut code = vec![];
+        let mut inner = vec![];
+        let n = elems.len();
+        for (_, e) in elems {
+            inner.push(e.0);
+        }
+        let inner = inner.join(", ");
+        if n == 1 {
+            code.push(format!("let {bind} = {inner};"));
+        } else {
+            code.push(format!("let {bind} = ({inner});"));
+        }
+        Ok(code)
+    }
+}
+fn scan_unit_type(bind: Bind, ty: &ast::UnitType) -> Code {
+    let v = unit_type_convert(ty, &next_token());
+    let ty = typing::unit_type(ty);
+    vec![format!("let {bind}: {ty} = {v};")]
+}
+fn next_token() -> String {
+    let input = shared_var("input");
+    format!("{input}.next().unwrap()")
+}
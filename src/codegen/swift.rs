@@ -22,6 +22,16 @@ impl readline::Lang for Swift {
         let Slice(xs, range) = source;
         let i = range.0;
         let j = range.1;
+        if let Some(n) = typing::tuple_len(ast) {
+            let mut inner = vec![];
+            for k in 0..n {
+                let v = format!("{xs}[{}]", i.clone() + Index(k.to_string()));
+                inner.push(unit_type_convert(&ast.0, &v));
+            }
+            let inner = inner.join(", ");
+            code.push(format!("let {bind}: {} = ({inner})", typing::array(ast)));
+            return code;
+        }
         code.push(format!("var {bind}: {} = []", typing::array(ast)));
         code.push(format!("for i in {i} ..< {j} {{"));
         let v = format!("{xs}[i]");
@@ -36,7 +46,7 @@ impl readline::Lang for Swift {
         let mut code = vec![];
         let inner_ty = typing::tuple_like(&ast.0);
         let n = &ast.1;
-        let n = Index(n.0.clone());
+        let n = Index(n.expr.clone());
         code.push(format!("var {bind}: [{inner_ty}] = []"));
         code.push(format!("for _ in 0..<{n} {{"));
 
@@ -86,7 +96,7 @@ fn unit_type_convert(ty: &ast::UnitType, v: &str) -> String {
     }
 }
 type Type = String;
-mod typing {
+pub mod typing {
     use super::*;
     pub fn unit_type(ty: &ast::UnitType) -> Type {
         match ty {
@@ -96,9 +106,20 @@ mod typing {
             ast::UnitType::Str => "String".to_string(),
         }
     }
+    // Short arrays of a constant length become tuples so they can be destructured.
+    // The standard library compares tuples of up to 6 elements.
+    pub fn tuple_len(ty: &ast::Array) -> Option<usize> {
+        match ty.1.const_num {
+            Some(ast::ConstNum(n)) if (2..=6).contains(&n) => Some(n),
+            _ => None,
+        }
+    }
     pub fn array(ty: &ast::Array) -> Type {
         let inner = &ty.0;
         let inner = unit_type(inner);
+        if let Some(n) = tuple_len(ty) {
+            return format!("({})", vec![inner; n].join(", "));
+        }
         format!("[{inner}]")
     }
     pub fn list(ty: &ast::List) -> Type {
@@ -0,0 +1,101 @@
+use super::swift::typing;
+use super::*;
+
+pub struct SwiftStream;
+impl stream::Lang for SwiftStream {
+    // The whole input is read at once and scanned by a byte cursor.
+    // A trailing 0 is appended as a sentinel so we don't need bound checks.
+    fn prelude() -> Code {
+        let read_token = shared_var("read_token");
+        let read_int = shared_var("read_int");
+        let mut code = vec![];
+        let buf = new_var();
+        let pos = new_var();
+        code.push(format!(
+            "let {buf} = [UInt8](FileHandle.standardInput.readDataToEndOfFile()) + [0]"
+        ));
+        code.push(format!("var {pos} = 0"));
+
+        code.push(format!("func {read_token}() -> String {{"));
+        code.push(format!(
+            "    while {buf}[{pos}] != 0 && {buf}[{pos}] <= 32 {{ {pos} += 1 }}"
+        ));
+        code.push(format!("    let start = {pos}"));
+        code.push(format!("    while {buf}[{pos}] > 32 {{ {pos} += 1 }}"));
+        code.push(format!(
+            "    return String(decoding: {buf}[start..<{pos}], as: UTF8.self)"
+        ));
+        code.push("}".to_string());
+
+        code.push(format!("func {read_int}() -> Int {{"));
+        code.push(format!(
+            "    while {buf}[{pos}] != 0 && {buf}[{pos}] <= 32 {{ {pos} += 1 }}"
+        ));
+        code.push("    var neg = false".to_string());
+        code.push(format!(
+            "    if {buf}[{pos}] == 45 {{ neg = true; {pos} += 1 }}"
+        ));
+        code.push("    var x = 0".to_string());
+        code.push(format!(
+            "    while 48 <= {buf}[{pos}] && {buf}[{pos}] <= 57 {{ x = x * 10 + Int({buf}[{pos}] - 48); {pos} += 1 }}"
+        ));
+        code.push("    return neg ? -x : x".to_string());
+        code.push("}".to_string());
+        code
+    }
+    fn unit_type(bind: Bind, ast: &ast::UnitType) -> Code {
+        let code = format!("let {bind} = {}", scan_unit_type(ast));
+        vec![code]
+    }
+    fn array(bind: Bind, ast: &ast::Array) -> Code {
+        let mut code = vec![];
+        let n = Index(ast.1.expr.clone());
+        if let Some(n) = typing::tuple_len(ast) {
+            let inner = vec![scan_unit_type(&ast.0); n].join(", ");
+            code.push(format!("let {bind}: {} = ({inner})", typing::array(ast)));
+            return code;
+        }
+        code.push(format!("var {bind}: {} = []", typing::array(ast)));
+        code.push(format!("{bind}.reserveCapacity({n})"));
+        code.push(format!("for _ in 0..<{n} {{"));
+        code.push(format!("    {bind}.append({})", scan_unit_type(&ast.0)));
+        code.push("}".to_string());
+        code
+    }
+    fn matrix(bind: Bind, ast: &ast::Matrix) -> Result<Code, Error> {
+        let mut code = vec![];
+        let inner_ty = typing::tuple_like(&ast.0);
+        let n = Index(ast.1.expr.clone());
+        code.push(format!("var {bind}: [{inner_ty}] = []"));
+        code.push(format!("{bind}.reserveCapacity({n})"));
+        code.push(format!("for _ in 0..<{n} {{"));
+
+        let mut inner_code = vec![];
+        let t = new_var();
+        inner_code.append(&mut Self::tuple_like(t.clone(), &ast.0)?);
+        inner_code.push(format!("{bind}.append({t})"));
+
+        append_code(&mut code, "    ", inner_code);
+        code.push("}".to_string());
+        Ok(code)
+    }
+    fn tuple(bind: Bind, elems: Vec<(&ast::TupleElem, Bind)>) -> Result<Code, Error> {
+        let mut inner = vec![];
+        for (_, e) in elems {
+            inner.push(e.0);
+        }
+        let inner = inner.join(",");
+        let code = format!("let {bind} = ({inner})");
+        Ok(vec![code])
+    }
+}
+fn scan_unit_type(ty: &ast::UnitType) -> String {
+    let read_token = shared_var("read_token");
+    let read_int = shared_var("read_int");
+    match ty {
+        ast::UnitType::Int => format!("{read_int}()"),
+        ast::UnitType::Int0 => format!("({read_int}() - 1)"),
+        ast::UnitType::Float => format!("Double({read_token}())!"),
+        ast::UnitType::Str => format!("{read_token}()"),
+    }
+}
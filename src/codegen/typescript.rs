@@ -0,0 +1,160 @@
+use super::*;
+
+pub struct TypeScript;
+impl readline::Lang for TypeScript {
+    // Node.js has no synchronous readline so we read the whole stdin at once
+    // and walk through the lines with a cursor.
+    fn prelude() -> Code {
+        let input = shared_var("input");
+        let cur = shared_var("cur");
+        vec![
+            format!("const {input}: string[] = require(\"fs\").readFileSync(\"/dev/stdin\", \"utf8\").split(\"\\n\");"),
+            format!("let {cur} = 0;"),
+        ]
+    }
+    fn read_line(bind: Bind) -> (Code, Index) {
+        let input = shared_var("input");
+        let cur = shared_var("cur");
+        let mut code = vec![];
+        code.push(format!(
+            "const {bind}: string[] = {input}[{cur}++].trim().split(\" \");"
+        ));
+        let n = new_var();
+        code.push(format!("const {n} = {bind}.length;"));
+        (code, Index(n.0))
+    }
+    fn unit_type(bind: Bind, ast: &ast::UnitType, source: Slice) -> Code {
+        let Slice(xs, range) = source;
+        let i = range.0;
+        let v = format!("{xs}[{i}]");
+        let ty = typing::unit_type::<false>(ast);
+        let code = format!(
+            "const {bind}: {ty} = {};",
+            unit_type_convert::<false>(ast, &v)
+        );
+        vec![code]
+    }
+    fn array(bind: Bind, ast: &ast::Array, source: Slice) -> Code {
+        let Slice(xs, range) = source;
+        let i = range.0;
+        let j = range.1;
+        let ty = typing::array::<false>(ast);
+        let code = format!(
+            "const {bind}: {ty} = {xs}.slice({i}, {j}).map((x) => {});",
+            unit_type_convert::<false>(&ast.0, "x")
+        );
+        vec![code]
+    }
+    fn matrix(bind: Bind, ast: &ast::Matrix) -> Result<Code, Error> {
+        let mut code = vec![];
+        let ty = typing::tuple_like::<false>(&ast.0);
+        let n = Index(ast.1.expr.clone());
+        code.push(format!("const {bind}: {ty}[] = [];"));
+        let k = new_var();
+        code.push(format!("for (let {k} = 0; {k} < {n}; {k}++) {{"));
+
+        let mut inner_code = vec![];
+        let line = new_var();
+        let (mut read_line, m) = Self::read_line(line.clone());
+        inner_code.append(&mut read_line);
+
+        let tuple = new_var();
+        let slice = Slice(line, Range(Index::zero(), m));
+        inner_code.append(&mut Self::tuple_like(tuple.clone(), &ast.0, slice)?);
+        inner_code.push(format!("{bind}.push({tuple});"));
+
+        append_code(&mut code, "\t", inner_code);
+        code.push("}".to_string());
+        Ok(code)
+    }
+    fn tuple(bind: Bind, elems: Vec<(&ast::TupleElem, Bind)>) -> Result<Code, Error> {
+        let code = typed_tuple::<false>(bind, elems);
+        Ok(vec![code])
+    }
+}
+pub fn typed_tuple<const BIGINT: bool>(bind: Bind, elems: Vec<(&ast::TupleElem, Bind)>) -> String {
+    let n = elems.len();
+    let mut inner = vec![];
+    let mut inner_ty = vec![];
+    for (e, var) in elems {
+        inner.push(var.0);
+        inner_ty.push(typing::tuple_elem::<BIGINT>(e));
+    }
+    let inner = inner.join(", ");
+    let inner_ty = inner_ty.join(", ");
+    if n == 1 {
+        format!("const {bind} = {inner};")
+    } else {
+        format!("const {bind}: [{inner_ty}] = [{inner}];")
+    }
+}
+// With BIGINT, integers are read as bigint to keep the precision beyond 2^53.
+pub fn unit_type_convert<const BIGINT: bool>(ty: &ast::UnitType, v: &str) -> String {
+    match ty {
+        ast::UnitType::Int if BIGINT => {
+            format!("BigInt({v})")
+        }
+        ast::UnitType::Int0 if BIGINT => {
+            format!("(BigInt({v}) - 1n)")
+        }
+        ast::UnitType::Int => {
+            format!("Number({v})")
+        }
+        ast::UnitType::Int0 => {
+            format!("(Number({v}) - 1)")
+        }
+        ast::UnitType::Float => {
+            format!("Number({v})")
+        }
+        ast::UnitType::Str => v.to_string(),
+    }
+}
+type Type = String;
+pub mod typing {
+    use super::*;
+    pub fn unit_type<const BIGINT: bool>(ty: &ast::UnitType) -> Type {
+        match ty {
+            ast::UnitType::Int if BIGINT => "bigint".to_string(),
+            ast::UnitType::Int0 if BIGINT => "bigint".to_string(),
+            ast::UnitType::Int => "number".to_string(),
+            ast::UnitType::Int0 => "number".to_string(),
+            ast::UnitType::Float => "number".to_string(),
+            ast::UnitType::Str => "string".to_string(),
+        }
+    }
+    pub fn array<const BIGINT: bool>(ty: &ast::Array) -> Type {
+        let inner = unit_type::<BIGINT>(&ty.0);
+        format!("{inner}[]")
+    }
+    pub fn list<const BIGINT: bool>(ty: &ast::List) -> Type {
+        let inner = unit_type::<BIGINT>(&ty.0);
+        format!("{inner}[]")
+    }
+    pub fn tuple_elem<const BIGINT: bool>(ty: &ast::TupleElem) -> Type {
+        match ty {
+            TupleElem::Array(x) => array::<BIGINT>(x),
+            TupleElem::List(x) => list::<BIGINT>(x),
+            TupleElem::UnitType(x) => unit_type::<BIGINT>(x),
+        }
+    }
+    pub fn tuple<const BIGINT: bool>(ty: &ast::Tuple) -> Type {
+        let mut inner = vec![];
+        let n = ty.0.len();
+        for e in &ty.0 {
+            inner.push(tuple_elem::<BIGINT>(e));
+        }
+        let inner = inner.join(", ");
+        if n == 1 {
+            inner.to_string()
+        } else {
+            format!("[{inner}]")
+        }
+    }
+    pub fn tuple_like<const BIGINT: bool>(ty: &ast::TupleLike) -> Type {
+        match ty {
+            ast::TupleLike::Array(x) => array::<BIGINT>(x),
+            ast::TupleLike::List(x) => list::<BIGINT>(x),
+            ast::TupleLike::Tuple(x) => tuple::<BIGINT>(x),
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use super::typescript::{typed_tuple, typing, unit_type_convert};
+use super::*;
+
+// With BIGINT, every integer is a bigint and the lengths are converted back to number.
+pub struct TypeScriptStream<const BIGINT: bool>;
+impl<const BIGINT: bool> stream::Lang for TypeScriptStream<BIGINT> {
+    fn prelude() -> Code {
+        let input = shared_var("input");
+        let cur = shared_var("cur");
+        vec![
+            format!("const {input}: string[] = require(\"fs\").readFileSync(\"/dev/stdin\", \"utf8\").trim().split(/\\s+/);"),
+            format!("let {cur} = 0;"),
+        ]
+    }
+    fn unit_type(bind: Bind, ast: &ast::UnitType) -> Code {
+        let ty = typing::unit_type::<BIGINT>(ast);
+        let v = unit_type_convert::<BIGINT>(ast, &next_token());
+        vec![format!("const {bind}: {ty} = {v};")]
+    }
+    fn array(bind: Bind, ast: &ast::Array) -> Code {
+        let mut code = vec![];
+        let ty = typing::array::<BIGINT>(ast);
+        let n = length::<BIGINT>(&ast.1);
+        code.push(format!("const {bind}: {ty} = new Array({n});"));
+        let k = new_var();
+        code.push(format!("for (let {k} = 0; {k} < {n}; {k}++) {{"));
+        let v = unit_type_convert::<BIGINT>(&ast.0, &next_token());
+        code.push(format!("\t{bind}[{k}] = {v};"));
+        code.push("}".to_string());
+        code
+    }
+    fn matrix(bind: Bind, ast: &ast::Matrix) -> Result<Code, Error> {
+        let mut code = vec![];
+        let ty = typing::tuple_like::<BIGINT>(&ast.0);
+        let n = length::<BIGINT>(&ast.1);
+        code.push(format!("const {bind}: {ty}[] = new Array({n});"));
+        let k = new_var();
+        code.push(format!("for (let {k} = 0; {k} < {n}; {k}++) {{"));
+
+        let mut inner_code = vec![];
+        let tuple = new_var();
+        inner_code.append(&mut Self::tuple_like(tuple.clone(), &ast.0)?);
+        inner_code.push(format!("{bind}[{k}] = {tuple};"));
+
+        append_code(&mut code, "\t", inner_code);
+        code.push("}".to_string());
+        Ok(code)
+    }
+    fn tuple(bind: Bind, elems: Vec<(&ast::TupleElem, Bind)>) -> Result<Code, Error> {
+        let code = typed_tuple::<BIGINT>(bind, elems);
+        Ok(vec![code])
+    }
+}
+fn next_token() -> String {
+    let input = shared_var("input");
+    let cur = shared_var("cur");
+    format!("{input}[{cur}++]")
+}
+// A bigint length like "n+1" needs the literals as bigint too: Number(n+1n).
+fn length<const BIGINT: bool>(len: &ast::Len) -> String {
+    let n = Index(len.expr.clone());
+    if !BIGINT {
+        return n.to_string();
+    }
+    let mut out = String::new();
+    let mut cur = String::new();
+    for c in n.to_string().chars().chain(std::iter::once(' ')) {
+        if c.is_ascii_alphanumeric() || c == '_' {
+            cur.push(c);
+            continue;
+        }
+        out.push_str(&cur);
+        if cur.starts_with(|c: char| c.is_ascii_digit()) {
+            out.push('n');
+        }
+        cur.clear();
+        out.push(c);
+    }
+    format!("Number({})", out.trim_end())
+}
@@ -0,0 +1,175 @@
+use super::*;
+
+// The caller passes an `allocator` in the scope.
+// Arrays of a constant length are arrays on the stack and the other arrays
+// are slices allocated from the allocator.
+pub struct Zig;
+impl stream::Lang for Zig {
+    fn prelude() -> Code {
+        let mut code = vec![];
+        let allocator = shared_var("allocator");
+        let br = new_var();
+        let input = new_var();
+        let tokens = shared_var("tokens");
+        code.push(format!("const {allocator} = allocator;"));
+        code.push(format!(
+            "var {br} = std.io.bufferedReader(std.io.getStdIn().reader());"
+        ));
+        code.push(format!(
+            "const {input} = try {br}.reader().readAllAlloc({allocator}, std.math.maxInt(usize));"
+        ));
+        code.push(format!(
+            "var {tokens} = std.mem.tokenize(u8, {input}, \" \\t\\r\\n\");"
+        ));
+        code
+    }
+    fn unit_type(bind: Bind, ast: &ast::UnitType) -> Code {
+        let code = format!("const {bind} = {};", scan_unit_type(ast));
+        vec![code]
+    }
+    fn array(bind: Bind, ast: &ast::Array) -> Code {
+        let mut code = vec![];
+        let ty = typing::unit_type(&ast.0);
+        let n = Index(ast.1.expr.clone());
+        let x = new_var();
+        if ast.1.const_num.is_some() {
+            code.push(format!("var {bind}: {} = undefined;", typing::array(ast)));
+            code.push(format!("for (&{bind}) |*{x}| {{"));
+        } else {
+            code.push(format!(
+                "const {bind} = try {}.alloc({ty}, @intCast({n}));",
+                shared_var("allocator")
+            ));
+            code.push(format!("for ({bind}) |*{x}| {{"));
+        }
+        code.push(format!("    {x}.* = {};", scan_unit_type(&ast.0)));
+        code.push("}".to_string());
+        code
+    }
+    // Each tuple type expression defines a distinct type in Zig.
+    // So we don't bind the tuple to a variable but assign the literal
+    // to the element so it is coerced to the element type.
+    fn matrix(bind: Bind, ast: &ast::Matrix) -> Result<Code, Error> {
+        let mut code = vec![];
+        let ty = typing::tuple_like(&ast.0);
+        let n = Index(ast.1.expr.clone());
+        code.push(format!(
+            "const {bind} = try {}.alloc({ty}, @intCast({n}));",
+            shared_var("allocator")
+        ));
+        let x = new_var();
+        code.push(format!("for ({bind}) |*{x}| {{"));
+
+        let mut inner_code = vec![];
+        match &ast.0 {
+            ast::TupleLike::Array(ast) => {
+                let v = new_var();
+                inner_code.append(&mut Self::array(v.clone(), ast));
+                inner_code.push(format!("{x}.* = {v};"));
+            }
+            ast::TupleLike::List(ast) => {
+                let v = new_var();
+                inner_code.append(&mut Self::list(v.clone(), ast));
+                inner_code.push(format!("{x}.* = {v};"));
+            }
+            ast::TupleLike::Tuple(ast::Tuple(elems)) => {
+                let mut inner = vec![];
+                for elem in elems {
+                    let v = new_var();
+                    match elem {
+                        TupleElem::UnitType(e) => {
+                            inner_code.append(&mut Self::unit_type(v.clone(), e));
+                        }
+                        TupleElem::Array(e) => {
+                            inner_code.append(&mut Self::array(v.clone(), e));
+                        }
+                        TupleElem::List(e) => {
+                            inner_code.append(&mut Self::list(v.clone(), e));
+                        }
+                    }
+                    inner.push(v.0);
+                }
+                if inner.len() == 1 {
+                    inner_code.push(format!("{x}.* = {};", inner[0]));
+                } else {
+                    inner_code.push(format!("{x}.* = .{{ {} }};", inner.join(", ")));
+                }
+            }
+        }
+        append_code(&mut code, "    ", inner_code);
+
+        code.push("}".to_string());
+        Ok(code)
+    }
+    fn tuple(bind: Bind, elems: Vec<(&ast::TupleElem, Bind)>) -> Result<Code, Error> {
+        let mut inner = vec![];
+        let n = elems.len();
+        for (_, e) in elems {
+            inner.push(e.0);
+        }
+        let inner = inner.join(", ");
+        let code = if n == 1 {
+            format!("const {bind} = {inner};")
+        } else {
+            format!("const {bind} = .{{ {inner} }};")
+        };
+        Ok(vec![code])
+    }
+}
+fn scan_unit_type(ty: &ast::UnitType) -> String {
+    let tokens = shared_var("tokens");
+    match ty {
+        ast::UnitType::Int => format!("try std.fmt.parseInt(i64, {tokens}.next().?, 10)"),
+        ast::UnitType::Int0 => format!("(try std.fmt.parseInt(i64, {tokens}.next().?, 10)) - 1"),
+        ast::UnitType::Float => format!("try std.fmt.parseFloat(f64, {tokens}.next().?)"),
+        ast::UnitType::Str => format!("{tokens}.next().?"),
+    }
+}
+type Type = String;
+mod typing {
+    use super::*;
+    pub fn unit_type(ty: &ast::UnitType) -> Type {
+        match ty {
+            ast::UnitType::Int => "i64".to_string(),
+            ast::UnitType::Int0 => "i64".to_string(),
+            ast::UnitType::Float => "f64".to_string(),
+            ast::UnitType::Str => "[]const u8".to_string(),
+        }
+    }
+    pub fn array(ty: &ast::Array) -> Type {
+        let inner = unit_type(&ty.0);
+        match ty.1.const_num {
+            Some(ast::ConstNum(n)) => format!("[{n}]{inner}"),
+            None => format!("[]{inner}"),
+        }
+    }
+    pub fn list(ty: &ast::List) -> Type {
+        let inner = unit_type(&ty.0);
+        format!("[]{inner}")
+    }
+    pub fn tuple(ty: &ast::Tuple) -> Type {
+        let mut inner = vec![];
+        let n = ty.0.len();
+        for e in &ty.0 {
+            let ty = match e {
+                TupleElem::Array(x) => array(x),
+                TupleElem::List(x) => list(x),
+                TupleElem::UnitType(x) => unit_type(x),
+            };
+            inner.push(ty);
+        }
+        let inner = inner.join(", ");
+        if n == 1 {
+            inner.to_string()
+        } else {
+            format!("struct {{ {inner} }}")
+        }
+    }
+    pub fn tuple_like(ty: &ast::TupleLike) -> Type {
+        match ty {
+            ast::TupleLike::Array(x) => array(x),
+            ast::TupleLike::List(x) => list(x),
+            ast::TupleLike::Tuple(x) => tuple(x),
+        }
+    }
+}
@@ -2,7 +2,9 @@ mod ast;
 mod codegen;
 mod parse;
 
+use std::collections::BTreeMap;
 use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
 
 static COUNTER: AtomicU64 = AtomicU64::new(0);
 pub fn new_id() -> String {
@@ -10,6 +12,25 @@ pub fn new_id() -> String {
     let name = format!("v{i}");
     name
 }
+// The same key gives the same id in a compilation.
+// This is for the helpers defined in a prelude and used by the rest of the code.
+static SHARED_IDS: Mutex<BTreeMap<&'static str, String>> = Mutex::new(BTreeMap::new());
+pub fn shared_id(key: &'static str) -> String {
+    let mut ids = SHARED_IDS.lock().unwrap();
+    ids.entry(key).or_insert_with(new_id).clone()
+}
+// Code which can't be placed in a function body, e.g. a class with member templates.
+static GLOBAL_CODE: Mutex<Vec<String>> = Mutex::new(Vec::new());
+pub fn push_global(code: Vec<String>) {
+    GLOBAL_CODE.lock().unwrap().extend(code);
+}
+
+pub struct Output {
+    // Goes at file scope.
+    pub global: String,
+    // Goes where the input is read.
+    pub main: String,
+}
 
 #[derive(PartialEq, Clone, Copy)]
 pub enum Lang {
@@ -17,18 +38,55 @@ pub enum Lang {
     Cpp,
     CppStream,
     Nim,
+    NimStream,
     Ruby,
+    RubyStream,
     Java,
     JavaStream,
     CSharp,
+    CSharpStream,
     Rust,
+    RustStream,
     Kotlin,
+    KotlinStream,
     GoStream,
     Swift,
+    SwiftStream,
+    TypeScript,
+    TypeScriptStream,
+    Haskell,
+    OCaml,
+    Julia,
+    JuliaDense,
+    Zig,
+    C,
+    Scala,
+    FSharp,
+    D,
+    Crystal,
+    Php,
+    Lua,
+    LuaStream,
+    LuaOneBased,
+    LuaStreamOneBased,
+    Dart,
+    Elixir,
+    Racket,
+    PythonNumpy,
+    RustProconio,
+    CppFast,
+    CppLib,
+    PythonLib,
+    RustLib,
+    CppStatic,
+    CppStreamStatic,
+    TypeScriptBigInt,
 }
 
-pub fn compile(lang: Lang, input: impl AsRef<str>) -> anyhow::Result<String> {
+pub fn compile(lang: Lang, input: impl AsRef<str>) -> anyhow::Result<Output> {
     COUNTER.store(0, Ordering::SeqCst);
+    SHARED_IDS.lock().unwrap().clear();
+    GLOBAL_CODE.lock().unwrap().clear();
 
     // https://github.com/rust-bakery/nom/issues/1571#issuecomment-1359257249
     let out = parse::parse(input.as_ref()).map_err(|e| e.to_owned())?.1;
@@ -37,14 +95,54 @@ pub fn compile(lang: Lang, input: impl AsRef<str>) -> anyhow::Result<String> {
         Lang::Cpp => codegen::readline::emit::<codegen::cpp::Cpp>(out),
         Lang::CppStream => codegen::stream::emit::<codegen::cpp_stream::CppStream>(out),
         Lang::Nim => codegen::readline::emit::<codegen::nim::Nim>(out),
+        Lang::NimStream => codegen::stream::emit::<codegen::nim_stream::NimStream>(out),
         Lang::Ruby => codegen::readline::emit::<codegen::ruby::Ruby>(out),
+        Lang::RubyStream => codegen::stream::emit::<codegen::ruby_stream::RubyStream>(out),
         Lang::Java => codegen::readline::emit::<codegen::java::Java>(out),
         Lang::JavaStream => codegen::stream::emit::<codegen::java_stream::JavaStream>(out),
         Lang::CSharp => codegen::readline::emit::<codegen::csharp::CSharp>(out),
+        Lang::CSharpStream => codegen::stream::emit::<codegen::csharp_stream::CSharpStream>(out),
         Lang::Rust => codegen::readline::emit::<codegen::rust::Rust>(out),
+        Lang::RustStream => codegen::stream::emit::<codegen::rust_stream::RustStream>(out),
         Lang::Kotlin => codegen::readline::emit::<codegen::kotlin::Kotlin>(out),
+        Lang::KotlinStream => codegen::stream::emit::<codegen::kotlin_stream::KotlinStream>(out),
         Lang::GoStream => codegen::stream::emit::<codegen::go_stream::GoStream>(out),
         Lang::Swift => codegen::readline::emit::<codegen::swift::Swift>(out),
+        Lang::SwiftStream => codegen::stream::emit::<codegen::swift_stream::SwiftStream>(out),
+        Lang::TypeScript => codegen::readline::emit::<codegen::typescript::TypeScript>(out),
+        Lang::TypeScriptStream => {
+            codegen::stream::emit::<codegen::typescript_stream::TypeScriptStream<false>>(out)
+        }
+        Lang::Haskell => codegen::haskell::emit(out),
+        Lang::OCaml => codegen::stream::emit::<codegen::ocaml::OCaml>(out),
+        Lang::Julia => codegen::readline::emit::<codegen::julia::Julia>(out),
+        Lang::JuliaDense => codegen::readline::emit::<codegen::julia::JuliaDense>(out),
+        Lang::Zig => codegen::stream::emit::<codegen::zig::Zig>(out),
+        Lang::C => codegen::stream::emit::<codegen::c::C>(out),
+        Lang::Scala => codegen::readline::emit::<codegen::scala::Scala>(out),
+        Lang::FSharp => codegen::readline::emit::<codegen::fsharp::FSharp>(out),
+        Lang::D => codegen::readline::emit::<codegen::d::D>(out),
+        Lang::Crystal => codegen::readline::emit::<codegen::crystal::Crystal>(out),
+        Lang::Php => codegen::php::emit(out),
+        Lang::Lua => codegen::readline::emit::<codegen::lua::Lua<true>>(out),
+        Lang::LuaStream => codegen::stream::emit::<codegen::lua::LuaStream<true>>(out),
+        Lang::LuaOneBased => codegen::readline::emit::<codegen::lua::Lua<false>>(out),
+        Lang::LuaStreamOneBased => codegen::stream::emit::<codegen::lua::LuaStream<false>>(out),
+        Lang::Dart => codegen::readline::emit::<codegen::dart::Dart>(out),
+        Lang::Elixir => codegen::stream::emit::<codegen::elixir::Elixir>(out),
+        Lang::Racket => codegen::racket::emit(out),
+        Lang::PythonNumpy => codegen::stream::emit::<codegen::python_numpy::PythonNumpy>(out),
+        Lang::RustProconio => codegen::rust_proconio::emit(out),
+        Lang::CppFast => codegen::stream::emit::<codegen::cpp_fast::CppFast>(out),
+        Lang::CppLib => codegen::stream::emit::<codegen::procon_input::CppLib>(out),
+        Lang::PythonLib => codegen::stream::emit::<codegen::procon_input::PythonLib>(out),
+        Lang::RustLib => codegen::stream::emit::<codegen::procon_input::RustLib>(out),
+        Lang::CppStatic => codegen::readline::emit::<codegen::cpp::CppStatic>(out),
+        Lang::CppStreamStatic => codegen::stream::emit::<codegen::cpp_stream::CppStreamStatic>(out),
+        Lang::TypeScriptBigInt => {
+            codegen::stream::emit::<codegen::typescript_stream::TypeScriptStream<true>>(out)
+        }
     }?;
-    Ok(out)
+    let global = GLOBAL_CODE.lock().unwrap().join("\n");
+    Ok(Output { global, main: out })
 }
@@ -1,9 +1,9 @@
 use nom::branch::alt;
 use nom::bytes::complete::tag;
 use nom::character::complete::{char, line_ending, none_of};
-use nom::combinator::{all_consuming, map};
+use nom::combinator::{all_consuming, map, map_res, opt};
 use nom::multi::{many1, separated_list0, separated_list1};
-use nom::sequence::{delimited, separated_pair};
+use nom::sequence::{delimited, pair, preceded, separated_pair};
 use nom::IResult;
 
 use crate::{ast::*, new_id};
@@ -25,22 +25,38 @@ fn parse_var(i: &str) -> IResult<&str, Var> {
     map(parse_indent, |x| Var(x.to_string()))(i)
 }
 fn parse_len(i: &str) -> IResult<&str, Len> {
-    let p = many1(none_of("]"));
-    map(p, |cs| {
+    let p = many1(none_of("]<"));
+    // An optional upper bound like "n <= 200000" is a capacity hint.
+    let capacity = map_res(parse_decimal, |s| s.replace('_', "").parse());
+    let capacity = opt(preceded(tag("<="), ws(capacity)));
+    map(pair(p, capacity), |(cs, capacity)| {
         let mut s = String::new();
         for c in cs {
             s.push(c);
         }
-        Len(s)
+        // The length is a constant if it is a numeric literal.
+        let const_num = all_consuming(ws(parse_const_num))(&s).ok().map(|(_, x)| x);
+        let expr = match const_num {
+            Some(ConstNum(n)) => n.to_string(),
+            None => s.trim().to_string(),
+        };
+        Len {
+            expr,
+            const_num,
+            capacity,
+        }
     })(i)
 }
+fn parse_const_num(i: &str) -> IResult<&str, ConstNum> {
+    map_res(parse_decimal, |s| s.replace('_', "").parse().map(ConstNum))(i)
+}
 fn parse_array(i: &str) -> IResult<&str, Array> {
     let p = separated_pair(ws(parse_unit_type), char(';'), ws(parse_len));
     let p = map(p, |(fi, la)| Array(fi, la));
     delimited(char('['), p, char(']'))(i)
 }
 fn parse_list(i: &str) -> IResult<&str, List> {
-    let p = map(ws(parse_unit_type), |x| List(x, Len(new_id())));
+    let p = map(ws(parse_unit_type), |x| List(x, Len::new(new_id())));
     delimited(char('['), p, char(']'))(i)
 }
 fn parse_tuple(i: &str) -> IResult<&str, Tuple> {
@@ -122,7 +138,8 @@ mod tests {
         ok!(parse_array, "[int; n-1]");
         ok!(parse_array, "[int; n+m]");
         ok!(parse_array, "[int; 2*n]");
-        ok!(parse_array, "[float; n]")
+        ok!(parse_array, "[float; n]");
+        ok!(parse_array, "[int; n <= 200000]")
     }
     #[test]
     fn test_list() {
@@ -140,6 +157,22 @@ mod tests {
         ok!(parse_len, "m");
         ok!(parse_len, "M");
         ok!(parse_len, "n+1");
+        ok!(parse_len, "n <= 200000");
+        ok!(parse_len, "n+1<=100_000");
+        assert_eq!(parse_len("n <= 10").unwrap().1.capacity, Some(10));
+        assert!(parse_len("n").unwrap().1.capacity.is_none());
+        err!(all_consuming(parse_len), "n <= m");
+    }
+    #[test]
+    fn test_const_num() {
+        ok!(parse_const_num, "3");
+        ok!(parse_const_num, "1_000");
+        err!(parse_const_num, "n");
+        assert_eq!(parse_len("3").unwrap().1.const_num.unwrap().0, 3);
+        assert_eq!(parse_len("10 ").unwrap().1.const_num.unwrap().0, 10);
+        assert_eq!(parse_len("1_000").unwrap().1.expr, "1000");
+        assert!(parse_len("n+1").unwrap().1.const_num.is_none());
+        assert!(parse_len("2*n").unwrap().1.const_num.is_none());
     }
     #[test]
     fn test_unit_type() {
@@ -43,26 +43,71 @@ struct TestTask<'a> {
 }
 #[derive(serde::Serialize)]
 struct Context {
+    global: String,
     parser: String,
     checker: String,
 }
 impl TestTask<'_> {
     fn exec(self) -> Result<ExecInfo> {
-        let parser = {
+        let Compiler::Output {
+            global,
+            main: parser,
+        } = {
             let parser = read(&self.case.parser)?;
             match self.lang_name.as_ref() {
                 "python" => Compiler::compile(Compiler::Lang::Python, &parser)?,
                 "cpp" => Compiler::compile(Compiler::Lang::Cpp, &parser)?,
                 "cpp-stream" => Compiler::compile(Compiler::Lang::CppStream, &parser)?,
                 "nim" => Compiler::compile(Compiler::Lang::Nim, &parser)?,
+                "nim-stream" => Compiler::compile(Compiler::Lang::NimStream, &parser)?,
                 "ruby" => Compiler::compile(Compiler::Lang::Ruby, &parser)?,
+                "ruby-stream" => Compiler::compile(Compiler::Lang::RubyStream, &parser)?,
                 "java" => Compiler::compile(Compiler::Lang::Java, &parser)?,
                 "java-stream" => Compiler::compile(Compiler::Lang::JavaStream, &parser)?,
                 "csharp" => Compiler::compile(Compiler::Lang::CSharp, &parser)?,
+                "csharp-stream" => Compiler::compile(Compiler::Lang::CSharpStream, &parser)?,
                 "rust" => Compiler::compile(Compiler::Lang::Rust, &parser)?,
+                "rust-stream" => Compiler::compile(Compiler::Lang::RustStream, &parser)?,
                 "kotlin" => Compiler::compile(Compiler::Lang::Kotlin, &parser)?,
+                "kotlin-stream" => Compiler::compile(Compiler::Lang::KotlinStream, &parser)?,
                 "go-stream" => Compiler::compile(Compiler::Lang::GoStream, &parser)?,
                 "swift" => Compiler::compile(Compiler::Lang::Swift, &parser)?,
+                "swift-stream" => Compiler::compile(Compiler::Lang::SwiftStream, &parser)?,
+                "typescript" => Compiler::compile(Compiler::Lang::TypeScript, &parser)?,
+                "typescript-stream" => {
+                    Compiler::compile(Compiler::Lang::TypeScriptStream, &parser)?
+                }
+                "haskell" => Compiler::compile(Compiler::Lang::Haskell, &parser)?,
+                "ocaml" => Compiler::compile(Compiler::Lang::OCaml, &parser)?,
+                "julia" => Compiler::compile(Compiler::Lang::Julia, &parser)?,
+                "julia-dense" => Compiler::compile(Compiler::Lang::JuliaDense, &parser)?,
+                "zig" => Compiler::compile(Compiler::Lang::Zig, &parser)?,
+                "c" => Compiler::compile(Compiler::Lang::C, &parser)?,
+                "scala" => Compiler::compile(Compiler::Lang::Scala, &parser)?,
+                "fsharp" => Compiler::compile(Compiler::Lang::FSharp, &parser)?,
+                "d" => Compiler::compile(Compiler::Lang::D, &parser)?,
+                "crystal" => Compiler::compile(Compiler::Lang::Crystal, &parser)?,
+                "php" => Compiler::compile(Compiler::Lang::Php, &parser)?,
+                "lua" => Compiler::compile(Compiler::Lang::Lua, &parser)?,
+                "lua-stream" => Compiler::compile(Compiler::Lang::LuaStream, &parser)?,
+                "lua-one-based" => Compiler::compile(Compiler::Lang::LuaOneBased, &parser)?,
+                "lua-stream-one-based" => {
+                    Compiler::compile(Compiler::Lang::LuaStreamOneBased, &parser)?
+                }
+                "dart" => Compiler::compile(Compiler::Lang::Dart, &parser)?,
+                "elixir" => Compiler::compile(Compiler::Lang::Elixir, &parser)?,
+                "racket" => Compiler::compile(Compiler::Lang::Racket, &parser)?,
+                "python-numpy" => Compiler::compile(Compiler::Lang::PythonNumpy, &parser)?,
+                "rust-proconio" => Compiler::compile(Compiler::Lang::RustProconio, &parser)?,
+                "cpp-fast" => Compiler::compile(Compiler::Lang::CppFast, &parser)?,
+                "cpp-lib" => Compiler::compile(Compiler::Lang::CppLib, &parser)?,
+                "python-lib" => Compiler::compile(Compiler::Lang::PythonLib, &parser)?,
+                "rust-lib" => Compiler::compile(Compiler::Lang::RustLib, &parser)?,
+                "cpp-static" => Compiler::compile(Compiler::Lang::CppStatic, &parser)?,
+                "cpp-stream-static" => Compiler::compile(Compiler::Lang::CppStreamStatic, &parser)?,
+                "typescript-bigint" => {
+                    Compiler::compile(Compiler::Lang::TypeScriptBigInt, &parser)?
+                }
                 _ => unreachable!(),
             }
         };
@@ -78,7 +123,11 @@ impl TestTask<'_> {
             let template = read(self.template)?;
             engine.add_template(self.lang_name, &template)?;
 
-            let ctx = Context { parser, checker };
+            let ctx = Context {
+                global,
+                parser,
+                checker,
+            };
             engine.render(self.lang_name, &ctx)?
         };
         let exec_file = self.target.join(format!(
@@ -386,22 +435,92 @@ struct BenchResult {
     cpp_stream: u64,
     #[tabled(rename = "Nim")]
     nim: u64,
+    #[tabled(rename = "Nim (Stream)")]
+    nim_stream: u64,
     #[tabled(rename = "Ruby")]
     ruby: u64,
+    #[tabled(rename = "Ruby (Stream)")]
+    ruby_stream: u64,
     #[tabled(rename = "Java")]
     java: u64,
     #[tabled(rename = "Java (Stream)")]
     java_stream: u64,
     #[tabled(rename = "C#")]
     csharp: u64,
+    #[tabled(rename = "C# (Stream)")]
+    csharp_stream: u64,
     #[tabled(rename = "Rust")]
     rust: u64,
+    #[tabled(rename = "Rust (Stream)")]
+    rust_stream: u64,
     #[tabled(rename = "Kotlin")]
     kotlin: u64,
+    #[tabled(rename = "Kotlin (Stream)")]
+    kotlin_stream: u64,
     #[tabled(rename = "Go (Stream)")]
     go_stream: u64,
     #[tabled(rename = "Swift")]
     swift: u64,
+    #[tabled(rename = "Swift (Stream)")]
+    swift_stream: u64,
+    #[tabled(rename = "TypeScript")]
+    typescript: u64,
+    #[tabled(rename = "TypeScript (Stream)")]
+    typescript_stream: u64,
+    #[tabled(rename = "Haskell")]
+    haskell: u64,
+    #[tabled(rename = "OCaml")]
+    ocaml: u64,
+    #[tabled(rename = "Julia")]
+    julia: u64,
+    #[tabled(rename = "Julia (Dense)")]
+    julia_dense: u64,
+    #[tabled(rename = "Zig")]
+    zig: u64,
+    #[tabled(rename = "C")]
+    c: u64,
+    #[tabled(rename = "Scala")]
+    scala: u64,
+    #[tabled(rename = "F#")]
+    fsharp: u64,
+    #[tabled(rename = "D")]
+    d: u64,
+    #[tabled(rename = "Crystal")]
+    crystal: u64,
+    #[tabled(rename = "PHP")]
+    php: u64,
+    #[tabled(rename = "Lua")]
+    lua: u64,
+    #[tabled(rename = "Lua (Stream)")]
+    lua_stream: u64,
+    #[tabled(rename = "Lua (1-based)")]
+    lua_one_based: u64,
+    #[tabled(rename = "Lua (Stream, 1-based)")]
+    lua_stream_one_based: u64,
+    #[tabled(rename = "Dart")]
+    dart: u64,
+    #[tabled(rename = "Elixir")]
+    elixir: u64,
+    #[tabled(rename = "Racket")]
+    racket: u64,
+    #[tabled(rename = "Python (NumPy)")]
+    python_numpy: u64,
+    #[tabled(rename = "Rust (proconio)")]
+    rust_proconio: u64,
+    #[tabled(rename = "C++ (Fast)")]
+    cpp_fast: u64,
+    #[tabled(rename = "C++ (Lib)")]
+    cpp_lib: u64,
+    #[tabled(rename = "Python (Lib)")]
+    python_lib: u64,
+    #[tabled(rename = "Rust (Lib)")]
+    rust_lib: u64,
+    #[tabled(rename = "C++ (Static)")]
+    cpp_static: u64,
+    #[tabled(rename = "C++ (Stream, Static)")]
+    cpp_stream_static: u64,
+    #[tabled(rename = "TypeScript (BigInt)")]
+    typescript_bigint: u64,
 }
 fn make_table(result: BTreeMap<u64, BTreeMap<String, Duration>>) -> String {
     let mut rows: Vec<BenchResult> = vec![];
@@ -415,14 +534,49 @@ fn make_table(result: BTreeMap<u64, BTreeMap<String, Duration>>) -> String {
                 "cpp" => row.cpp = du,
                 "cpp-stream" => row.cpp_stream = du,
                 "nim" => row.nim = du,
+                "nim-stream" => row.nim_stream = du,
                 "ruby" => row.ruby = du,
+                "ruby-stream" => row.ruby_stream = du,
                 "java" => row.java = du,
                 "java-stream" => row.java_stream = du,
                 "csharp" => row.csharp = du,
+                "csharp-stream" => row.csharp_stream = du,
                 "rust" => row.rust = du,
+                "rust-stream" => row.rust_stream = du,
                 "kotlin" => row.kotlin = du,
+                "kotlin-stream" => row.kotlin_stream = du,
                 "go-stream" => row.go_stream = du,
                 "swift" => row.swift = du,
+                "swift-stream" => row.swift_stream = du,
+                "typescript" => row.typescript = du,
+                "typescript-stream" => row.typescript_stream = du,
+                "haskell" => row.haskell = du,
+                "ocaml" => row.ocaml = du,
+                "julia" => row.julia = du,
+                "julia-dense" => row.julia_dense = du,
+                "zig" => row.zig = du,
+                "c" => row.c = du,
+                "scala" => row.scala = du,
+                "fsharp" => row.fsharp = du,
+                "d" => row.d = du,
+                "crystal" => row.crystal = du,
+                "php" => row.php = du,
+                "lua" => row.lua = du,
+                "lua-stream" => row.lua_stream = du,
+                "lua-one-based" => row.lua_one_based = du,
+                "lua-stream-one-based" => row.lua_stream_one_based = du,
+                "dart" => row.dart = du,
+                "elixir" => row.elixir = du,
+                "racket" => row.racket = du,
+                "python-numpy" => row.python_numpy = du,
+                "rust-proconio" => row.rust_proconio = du,
+                "cpp-fast" => row.cpp_fast = du,
+                "cpp-lib" => row.cpp_lib = du,
+                "python-lib" => row.python_lib = du,
+                "rust-lib" => row.rust_lib = du,
+                "cpp-static" => row.cpp_static = du,
+                "cpp-stream-static" => row.cpp_stream_static = du,
+                "typescript-bigint" => row.typescript_bigint = du,
                 _ => unreachable!(),
             }
         }